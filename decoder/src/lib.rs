@@ -0,0 +1,372 @@
+//! Host-side decoder for `μfmt`'s deferred formatting backend
+//!
+//! The firmware writes frames with `ufmt::uwrite_deferred!`; this crate turns them back into text
+//! using the format strings interned in the `ufmt_deferred` section of the firmware's ELF file.
+//!
+//! ``` no_run
+//! use ufmt_decoder::Table;
+//!
+//! let elf = std::fs::read("firmware.elf").unwrap();
+//! let table = Table::parse(&elf).unwrap();
+//!
+//! let mut bytes: &[u8] = &[/* read from the device */];
+//! while !bytes.is_empty() {
+//!     let (text, used) = table.decode(bytes).unwrap();
+//!     print!("{}", text);
+//!     bytes = &bytes[used..];
+//! }
+//! ```
+
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+use std::{collections::BTreeMap, fmt, str};
+
+use object::{Object, ObjectSection, ObjectSymbol};
+
+const SECTION: &str = "ufmt_deferred";
+const SYMBOL_PREFIX: &str = "ufmt_deferred:";
+
+// must match the tags in `ufmt::deferred`
+const TAG_UNSIGNED: u8 = 0x00;
+const TAG_SIGNED: u8 = 0x10;
+const TAG_BOOL: u8 = 0x20;
+const TAG_CHAR: u8 = 0x30;
+const TAG_STR: u8 = 0x40;
+
+/// Errors that can occur while building the table or decoding a frame
+#[derive(Debug)]
+pub enum Error {
+    /// The ELF file could not be parsed
+    Object(object::Error),
+    /// The ELF file contains no `ufmt_deferred` section
+    MissingSection,
+    /// An interned format string could not be parsed
+    InvalidFormat(String),
+    /// The frame refers to a format string that is not in the table
+    UnknownIndex(u64),
+    /// The frame ended before all the arguments were decoded
+    UnexpectedEof,
+    /// An argument has an unknown tag
+    InvalidTag(u8),
+    /// An argument does not hold a valid value (e.g. a string that's not UTF-8)
+    InvalidValue,
+    /// The argument cannot be formatted in the requested way (e.g. `{:x}` on a string)
+    MismatchedArgument,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Object(e) => write!(f, "could not parse the ELF file: {}", e),
+            Error::MissingSection => write!(f, "`{}` section not found", SECTION),
+            Error::InvalidFormat(s) => write!(f, "invalid interned format string: {:?}", s),
+            Error::UnknownIndex(i) => write!(f, "unknown format string index: {}", i),
+            Error::UnexpectedEof => f.write_str("unexpected end of frame"),
+            Error::InvalidTag(t) => write!(f, "invalid argument tag: {:#04x}", t),
+            Error::InvalidValue => f.write_str("invalid argument value"),
+            Error::MismatchedArgument => f.write_str("argument does not match its format piece"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<object::Error> for Error {
+    fn from(e: object::Error) -> Self {
+        Error::Object(e)
+    }
+}
+
+/// The format strings interned in a firmware image, indexed by their position in the
+/// `ufmt_deferred` section
+pub struct Table {
+    entries: BTreeMap<u64, Vec<Piece>>,
+}
+
+impl Table {
+    /// Builds the table from the contents of an ELF file
+    pub fn parse(elf: &[u8]) -> Result<Self, Error> {
+        let file = object::File::parse(elf)?;
+        let section = file.section_by_name(SECTION).ok_or(Error::MissingSection)?;
+
+        let mut entries = BTreeMap::new();
+        for symbol in file.symbols() {
+            if symbol.section_index() != Some(section.index()) {
+                continue;
+            }
+
+            let name = symbol.name()?;
+            if let Some(rest) = name.strip_prefix(SYMBOL_PREFIX) {
+                // skip the crate name and the disambiguator
+                let format = rest
+                    .splitn(3, ':')
+                    .nth(2)
+                    .ok_or_else(|| Error::InvalidFormat(name.to_string()))?;
+
+                entries.insert(symbol.address() - section.address(), parse(format)?);
+            }
+        }
+
+        Ok(Table { entries })
+    }
+
+    /// Decodes the frame at the start of `bytes`
+    ///
+    /// Returns the formatted text and the number of bytes the frame spans
+    pub fn decode(&self, bytes: &[u8]) -> Result<(String, usize), Error> {
+        let mut cursor = Cursor { bytes, pos: 0 };
+
+        let index = cursor.varint()? as u64;
+        let pieces = self.entries.get(&index).ok_or(Error::UnknownIndex(index))?;
+
+        let mut text = String::new();
+        for piece in pieces {
+            match piece {
+                Piece::Str(s) => text.push_str(s),
+                _ => cursor.value()?.format(piece, &mut text)?,
+            }
+        }
+
+        Ok((text, cursor.pos))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Str(String),
+    Display,
+    Debug,
+    Hex {
+        upper_case: bool,
+        zero_pad: bool,
+        pad_length: usize,
+        prefix: bool,
+    },
+}
+
+// parses the normalized format strings emitted by `uwrite_deferred!`
+fn parse(format: &str) -> Result<Vec<Piece>, Error> {
+    let err = || Error::InvalidFormat(format.to_string());
+
+    let mut pieces = vec![];
+    let mut buf = String::new();
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if let Some(tail) = rest.strip_prefix("{{") {
+            buf.push('{');
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("}}") {
+            buf.push('}');
+            rest = tail;
+        } else if c == '{' {
+            let end = rest.find('}').ok_or_else(err)?;
+            let spec = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            if !buf.is_empty() {
+                pieces.push(Piece::Str(std::mem::take(&mut buf)));
+            }
+
+            pieces.push(match spec {
+                "" => Piece::Display,
+                ":?" | ":#?" => Piece::Debug,
+                _ => {
+                    let spec = spec.strip_prefix(':').ok_or_else(err)?;
+                    let (spec, prefix) = match spec.strip_prefix('#') {
+                        Some(tail) => (tail, true),
+                        None => (spec, false),
+                    };
+                    let (spec, zero_pad) = match spec.strip_prefix('0') {
+                        Some(tail) => (tail, true),
+                        None => (spec, false),
+                    };
+                    let (digits, upper_case) = if let Some(digits) = spec.strip_suffix('x') {
+                        (digits, false)
+                    } else if let Some(digits) = spec.strip_suffix('X') {
+                        (digits, true)
+                    } else {
+                        return Err(err());
+                    };
+                    let pad_length = if digits.is_empty() {
+                        0
+                    } else {
+                        digits.parse().map_err(|_| err())?
+                    };
+
+                    Piece::Hex {
+                        upper_case,
+                        zero_pad,
+                        pad_length,
+                        prefix,
+                    }
+                }
+            });
+        } else if c == '}' {
+            return Err(err());
+        } else {
+            buf.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    if !buf.is_empty() {
+        pieces.push(Piece::Str(buf));
+    }
+
+    Ok(pieces)
+}
+
+enum Value {
+    Unsigned(u128),
+    // the value and its size in bits
+    Signed(i128, u32),
+    Bool(bool),
+    Char(char),
+    Str(String),
+}
+
+impl Value {
+    fn format(&self, piece: &Piece, text: &mut String) -> Result<(), Error> {
+        use std::fmt::Write as _;
+
+        let _ = match (self, piece) {
+            (Value::Unsigned(n), Piece::Display | Piece::Debug) => write!(text, "{}", n),
+            (Value::Signed(n, _), Piece::Display | Piece::Debug) => write!(text, "{}", n),
+            (Value::Bool(b), Piece::Display | Piece::Debug) => write!(text, "{}", b),
+            (Value::Char(c), Piece::Display) => write!(text, "{}", c),
+            (Value::Char(c), Piece::Debug) => write!(text, "{:?}", c),
+            (Value::Str(s), Piece::Display) => write!(text, "{}", s),
+            (Value::Str(s), Piece::Debug) => write!(text, "{:?}", s),
+            (
+                Value::Unsigned(_) | Value::Signed(..),
+                &Piece::Hex {
+                    upper_case,
+                    zero_pad,
+                    pad_length: w,
+                    prefix,
+                },
+            ) => {
+                let n = match *self {
+                    Value::Signed(n, bits) => (n as u128) & (u128::MAX >> (128 - bits)),
+                    Value::Unsigned(n) => n,
+                    _ => unreachable!(),
+                };
+
+                match (upper_case, zero_pad, prefix) {
+                    (false, false, false) => write!(text, "{:w$x}", n),
+                    (false, false, true) => write!(text, "{:#w$x}", n),
+                    (false, true, false) => write!(text, "{:0w$x}", n),
+                    (false, true, true) => write!(text, "{:#0w$x}", n),
+                    (true, false, false) => write!(text, "{:w$X}", n),
+                    (true, false, true) => write!(text, "{:#w$X}", n),
+                    (true, true, false) => write!(text, "{:0w$X}", n),
+                    (true, true, true) => write!(text, "{:#0w$X}", n),
+                }
+            }
+            _ => return Err(Error::MismatchedArgument),
+        };
+
+        Ok(())
+    }
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn byte(&mut self) -> Result<u8, Error> {
+        let byte = *self.bytes.get(self.pos).ok_or(Error::UnexpectedEof)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn varint(&mut self) -> Result<u128, Error> {
+        let mut n = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            if shift >= 128 {
+                return Err(Error::InvalidValue);
+            }
+            n |= u128::from(byte & 0x7f) << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        let tag = self.byte()?;
+        if tag & 0x0f > 4 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let bits = 8 << (tag & 0x0f);
+
+        Ok(match tag & 0xf0 {
+            TAG_UNSIGNED => Value::Unsigned(self.varint()?),
+            TAG_SIGNED => {
+                let n = self.varint()?;
+                Value::Signed(((n >> 1) as i128) ^ -((n & 1) as i128), bits)
+            }
+            TAG_BOOL => Value::Bool(self.byte()? != 0),
+            TAG_CHAR => {
+                let n = self.varint()?;
+                Value::Char(
+                    u32::try_from(n)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or(Error::InvalidValue)?,
+                )
+            }
+            TAG_STR => {
+                let len = usize::try_from(self.varint()?).map_err(|_| Error::InvalidValue)?;
+                let end = self.pos.checked_add(len).ok_or(Error::UnexpectedEof)?;
+                let bytes = self.bytes.get(self.pos..end).ok_or(Error::UnexpectedEof)?;
+                self.pos = end;
+
+                Value::Str(
+                    str::from_utf8(bytes)
+                        .map_err(|_| Error::InvalidValue)?
+                        .to_string(),
+                )
+            }
+            _ => return Err(Error::InvalidTag(tag)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Piece;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            super::parse("{{x}} = {:#010X}\n").unwrap(),
+            vec![
+                Piece::Str("{x} = ".to_string()),
+                Piece::Hex {
+                    upper_case: true,
+                    zero_pad: true,
+                    pad_length: 10,
+                    prefix: true,
+                },
+                Piece::Str("\n".to_string()),
+            ]
+        );
+
+        assert_eq!(
+            super::parse("{}{:?}{:#?}").unwrap(),
+            vec![Piece::Display, Piece::Debug, Piece::Debug]
+        );
+
+        assert!(super::parse("{").is_err());
+        assert!(super::parse("}").is_err());
+        assert!(super::parse("{:q}").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+//! Decodes frames written by this very test binary
+
+use std::{env, fs};
+
+use ufmt::uwrite_deferred;
+use ufmt_decoder::Table;
+
+#[test]
+fn roundtrip() {
+    let elf = fs::read(env::current_exe().unwrap()).unwrap();
+    let table = Table::parse(&elf).unwrap();
+
+    let mut bytes = vec![];
+    let mut expected = vec![];
+
+    uwrite_deferred!(bytes, "Hello, world!").unwrap();
+    expected.push("Hello, world!".to_string());
+
+    uwrite_deferred!(&mut bytes, "{} {} {} {}", 0u8, u128::MAX, i64::MIN, -1isize).unwrap();
+    expected.push(format!("{} {} {} {}", 0u8, u128::MAX, i64::MIN, -1isize));
+
+    uwrite_deferred!(
+        bytes,
+        "{:x} {:#06X} {:4x} {:#x}",
+        -1i8,
+        300u16,
+        7u32,
+        i128::MIN
+    )
+    .unwrap();
+    expected.push(format!(
+        "{:x} {:#06X} {:4x} {:#x}",
+        -1i8,
+        300u16,
+        7u32,
+        i128::MIN
+    ));
+
+    let name = "ufmt";
+    uwrite_deferred!(
+        bytes,
+        "{{{}}} {:?} {} {:?} {:#?}\n",
+        name,
+        name,
+        'μ',
+        '\n',
+        true
+    )
+    .unwrap();
+    expected.push(format!(
+        "{{{}}} {:?} {} {:?} {:#?}\n",
+        name, name, 'μ', '\n', true
+    ));
+
+    // every expansion gets its own symbol even though they share the call site
+    macro_rules! log {
+        ($($arg:expr),*) => {
+            uwrite_deferred!(bytes, "log: {}", $($arg),*).unwrap();
+        };
+    }
+    log!(1u8);
+    log!(2u8);
+    expected.push("log: 1".to_string());
+    expected.push("log: 2".to_string());
+
+    let mut rest = &bytes[..];
+    for expected in expected {
+        let (text, used) = table.decode(rest).unwrap();
+        assert_eq!(text, expected);
+        rest = &rest[used..];
+    }
+    assert!(rest.is_empty());
+}
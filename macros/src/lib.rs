@@ -8,6 +8,8 @@ use core::mem;
 use proc_macro::TokenStream;
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 use proc_macro2::{Literal, Span};
 use quote::quote;
@@ -171,6 +173,296 @@ pub fn debug(input: TokenStream) -> TokenStream {
     ts.into()
 }
 
+/// Automatically derive the `uDebugMaxLen` trait for a `struct` or `enum`
+///
+/// The derived `MAX_LEN` bounds the output of the derived `uDebug` implementation
+#[proc_macro_derive(uDebugMaxLen)]
+pub fn debug_max_len(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let mut generics = input.generics;
+
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(parse_quote!(ufmt::uDebugMaxLen));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let ident = &input.ident;
+    let max_len = match input.data {
+        Data::Struct(data) => fields_max_len(&ident.to_string(), &data.fields),
+
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|var| fields_max_len(&var.ident.to_string(), &var.fields))
+            .reduce(|a, b| quote!(ufmt::unstable_max(#a, #b)))
+            .unwrap_or_else(|| quote!(0)),
+
+        Data::Union(..) => {
+            return parse::Error::new(Span::call_site(), "this trait cannot be derived for unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    quote!(
+        impl #impl_generics ufmt::uDebugMaxLen for #ident #ty_generics #where_clause {
+            const MAX_LEN: usize = #max_len;
+        }
+    )
+    .into()
+}
+
+/// Automatically derive the `uError` trait for a `struct` or `enum`
+///
+/// The field marked with `#[source]`, if any, is reported as the source of the error; there can be
+/// at most one per `struct` or `enum` variant.
+///
+/// If the `struct`, or every variant of the `enum`, has a `#[uerror("format string", args..)]`
+/// attribute then `uDisplay` is derived as well. The arguments are `uwrite!` arguments in which
+/// the fields are in scope by name; tuple fields are named `_0`, `_1`, etc.
+///
+/// Type parameters must implement `uDebug` and `uDisplay`
+#[proc_macro_derive(uError, attributes(source, uerror))]
+pub fn error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match error_(input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn error_(input: DeriveInput) -> parse::Result<proc_macro2::TokenStream> {
+    let mut generics = input.generics;
+
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(parse_quote!(ufmt::uDebug));
+            type_param.bounds.push(parse_quote!(ufmt::uDisplay));
+        }
+    }
+
+    let ident = &input.ident;
+    // (pattern, source, message) of each struct / variant
+    let mut cases = vec![];
+    match &input.data {
+        Data::Struct(data) => {
+            let (pat, source) = error_fields(quote!(#ident), &data.fields)?;
+            cases.push((pat, source, error_message(&input.attrs)?));
+        }
+
+        Data::Enum(data) => {
+            if let Some(attr) = input.attrs.iter().find(|attr| attr.path.is_ident("uerror")) {
+                return Err(parse::Error::new(
+                    attr.span(),
+                    "`#[uerror]` goes on the variants of an enum",
+                ));
+            }
+
+            for var in &data.variants {
+                let variant = &var.ident;
+                let (pat, source) = error_fields(quote!(#ident::#variant), &var.fields)?;
+                cases.push((pat, source, error_message(&var.attrs)?));
+            }
+        }
+
+        Data::Union(..) => {
+            return Err(parse::Error::new(
+                Span::call_site(),
+                "this trait cannot be derived for unions",
+            ));
+        }
+    }
+
+    let mut source_generics = generics.clone();
+    let predicates = &mut source_generics.make_where_clause().predicates;
+    for (_, source, _) in &cases {
+        if let Some((_, ty)) = source {
+            predicates.push(parse_quote!(#ty: ufmt::error::uError));
+        }
+    }
+
+    let arms = cases
+        .iter()
+        .map(|(pat, source, _)| match source {
+            Some((binding, _)) => {
+                quote!(#pat => core::option::Option::Some(visitor.visit(#binding)))
+            }
+            None => quote!(#pat => core::option::Option::None),
+        })
+        .collect::<Vec<_>>();
+
+    let body = if arms.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote!(
+            match self {
+                #(#arms,)*
+            }
+        )
+    };
+
+    let (impl_generics, ty_generics, where_clause) = source_generics.split_for_impl();
+    let mut ts = quote!(
+        impl #impl_generics ufmt::error::uError for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn source<V>(&self, visitor: V) -> core::option::Option<V::Output>
+            where
+                V: ufmt::error::SourceVisitor,
+            {
+                #body
+            }
+        }
+    );
+
+    let messages = cases
+        .iter()
+        .filter(|(_, _, message)| message.is_some())
+        .count();
+    if messages != 0 && messages != cases.len() {
+        return Err(parse::Error::new(
+            Span::call_site(),
+            "either every variant or none of them must have a `#[uerror]` attribute",
+        ));
+    }
+
+    // NOTE this includes enums without variants
+    if messages == cases.len() {
+        let arms = cases
+            .iter()
+            .map(|(pat, _, message)| {
+                let (literal, args) = message.as_ref().expect("UNREACHABLE");
+                quote!(#pat => ufmt::uwrite!(f, #literal #(, #args)*))
+            })
+            .collect::<Vec<_>>();
+
+        let body = if arms.is_empty() {
+            quote!(match *self {})
+        } else {
+            quote!(
+                match self {
+                    #(#arms,)*
+                }
+            )
+        };
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        ts.extend(quote!(
+            impl #impl_generics ufmt::uDisplay for #ident #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
+                where
+                    W: ufmt::uWrite + ?Sized,
+                {
+                    #body
+                }
+            }
+        ));
+    }
+
+    Ok(ts)
+}
+
+// a pattern that binds all the `fields` of `path` and the binding and type of the `#[source]`
+// field, if any
+#[allow(clippy::type_complexity)]
+fn error_fields(
+    path: proc_macro2::TokenStream,
+    fields: &Fields,
+) -> parse::Result<(proc_macro2::TokenStream, Option<(Ident, &syn::Type)>)> {
+    let mut bindings = vec![];
+    let mut source = None;
+    for (i, field) in fields.iter().enumerate() {
+        let binding = field
+            .ident
+            .clone()
+            .unwrap_or_else(|| Ident::new(&format!("_{}", i), Span::call_site()));
+
+        if let Some(attr) = field.attrs.iter().find(|attr| attr.path.is_ident("source")) {
+            if source.is_some() {
+                return Err(parse::Error::new(
+                    attr.span(),
+                    "there can only be one `#[source]` field",
+                ));
+            }
+
+            source = Some((binding.clone(), &field.ty));
+        }
+
+        bindings.push(binding);
+    }
+
+    let pat = match fields {
+        Fields::Named(_) => quote!(#path { #(#bindings),* }),
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => quote!(#path),
+    };
+
+    Ok((pat, source))
+}
+
+// the format string and arguments of the `#[uerror]` attribute, if any
+fn error_message(attrs: &[syn::Attribute]) -> parse::Result<Option<(LitStr, Vec<Expr>)>> {
+    let attr = match attrs.iter().find(|attr| attr.path.is_ident("uerror")) {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+
+    attr.parse_args_with(|input: ParseStream| {
+        let literal = input.parse::<LitStr>()?;
+        let mut args = vec![];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            args.push(input.parse()?);
+        }
+
+        Ok(Some((literal, args)))
+    })
+}
+
+// length of the `uDebug` output of `name` with the given `fields`, as produced by the
+// `DebugStruct` / `DebugTuple` helpers
+fn fields_max_len(name: &str, fields: &Fields) -> proc_macro2::TokenStream {
+    let (fixed, tys) = match fields {
+        Fields::Named(fields) if !fields.named.is_empty() => {
+            // " { " + "name: " + value + ", " + .. + " }"
+            let names = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().expect("UNREACHABLE").to_string().len() + 2)
+                .sum::<usize>();
+
+            (
+                name.len() + 3 + names + 2 * (fields.named.len() - 1) + 2,
+                fields
+                    .named
+                    .iter()
+                    .map(|field| &field.ty)
+                    .collect::<Vec<_>>(),
+            )
+        }
+
+        Fields::Unnamed(fields) if !fields.unnamed.is_empty() => {
+            // "(" + value + ", " + .. + ")"
+            (
+                name.len() + 1 + 2 * (fields.unnamed.len() - 1) + 1,
+                fields.unnamed.iter().map(|field| &field.ty).collect(),
+            )
+        }
+
+        _ => (name.len(), vec![]),
+    };
+
+    quote!(#fixed #(+ <#tys as ufmt::uDebugMaxLen>::MAX_LEN)*)
+}
+
 #[proc_macro]
 pub fn uwrite(input: TokenStream) -> TokenStream {
     write(input, false)
@@ -185,7 +477,7 @@ fn write(input: TokenStream, newline: bool) -> TokenStream {
     let input = parse_macro_input!(input as Input);
 
     let formatter = &input.formatter;
-    let literal = input.literal;
+    let literal = &input.literal;
 
     let mut format = literal.value();
     if newline {
@@ -196,31 +488,8 @@ fn write(input: TokenStream, newline: bool) -> TokenStream {
         Ok(pieces) => pieces,
     };
 
-    let required_args = pieces.iter().filter(|piece| !piece.is_str()).count();
-    let supplied_args = input.args.len();
-    match supplied_args.cmp(&required_args) {
-        Ordering::Less => {
-            return parse::Error::new(
-                literal.span(),
-                &format!(
-                    "format string requires {} arguments but {} {} supplied",
-                    required_args,
-                    supplied_args,
-                    if supplied_args == 1 { "was" } else { "were" }
-                ),
-            )
-            .to_compile_error()
-            .into();
-        }
-        Ordering::Greater => {
-            return parse::Error::new(
-                input.args[required_args].span(),
-                "argument never used".to_string(),
-            )
-            .to_compile_error()
-            .into();
-        }
-        Ordering::Equal => {}
+    if let Err(e) = check_args(&input, &pieces) {
+        return e.to_compile_error().into();
     }
 
     let mut args = vec![];
@@ -280,6 +549,224 @@ fn write(input: TokenStream, newline: bool) -> TokenStream {
     .into()
 }
 
+/// Parses structured input according to a format string
+///
+/// See `ufmt::uscan!` for details
+#[proc_macro]
+pub fn uscan(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+
+    let scanned = &input.formatter;
+    let literal = &input.literal;
+
+    let format = literal.value();
+    let pieces = match parse(&format, literal.span()) {
+        Err(e) => return e.to_compile_error().into(),
+        Ok(pieces) => pieces,
+    };
+
+    if let Err(e) = check_args(&input, &pieces) {
+        return e.to_compile_error().into();
+    }
+
+    let mut stmts = vec![];
+    let mut args = input.args.iter();
+    for (i, piece) in pieces.iter().enumerate() {
+        let field = match piece {
+            Piece::Str(s) => {
+                let s: &str = s;
+                stmts.push(quote!(__scanner.literal(#i, #s)?;));
+                continue;
+            }
+
+            Piece::Display | Piece::Hex { .. } => match pieces.get(i + 1) {
+                Some(Piece::Str(delimiter)) => {
+                    let delimiter: &str = delimiter;
+                    quote!(__scanner.field(#i, #delimiter)?)
+                }
+                Some(_) => {
+                    return parse::Error::new(
+                        literal.span(),
+                        "adjacent fields are ambiguous; separate them with a literal",
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+                None => quote!(__scanner.rest()),
+            },
+
+            Piece::Debug { .. } => {
+                return parse::Error::new(
+                    literal.span(),
+                    "invalid format string: `uscan!` only supports `{}` and `{:x}` fields",
+                )
+                .to_compile_error()
+                .into();
+            }
+        };
+
+        let arg = args.next().expect("UNREACHABLE");
+        stmts.push(if piece.is_hex() {
+            quote!(ufmt::UnstableScanHex::scan_hex(&mut (#arg), #i, #field)?;)
+        } else {
+            quote!(ufmt::UnstableScanField::scan_field(&mut (#arg), #i, #field)?;)
+        });
+    }
+
+    let last = pieces.len().saturating_sub(1);
+    quote!({
+        let mut __scanner = ufmt::UnstableScanner::new(#scanned);
+
+        (|| -> core::result::Result<(), ufmt::ScanError> {
+            #(#stmts)*
+            __scanner.finish(#last)
+        })()
+    })
+    .into()
+}
+
+fn check_args(input: &Input, pieces: &[Piece]) -> parse::Result<()> {
+    let required_args = pieces.iter().filter(|piece| !piece.is_str()).count();
+    let supplied_args = input.args.len();
+    match supplied_args.cmp(&required_args) {
+        Ordering::Less => Err(parse::Error::new(
+            input.literal.span(),
+            format!(
+                "format string requires {} arguments but {} {} supplied",
+                required_args,
+                supplied_args,
+                if supplied_args == 1 { "was" } else { "were" }
+            ),
+        )),
+        Ordering::Greater => Err(parse::Error::new(
+            input.args[required_args].span(),
+            "argument never used".to_string(),
+        )),
+        Ordering::Equal => Ok(()),
+    }
+}
+
+/// Writes a frame of the deferred formatting backend into a byte sink
+///
+/// The format string is interned in the `ufmt_deferred` linker section; only its index and the
+/// encoded arguments are written at runtime. See `ufmt::deferred` for details.
+#[proc_macro]
+pub fn uwrite_deferred(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+
+    let sink = &input.formatter;
+    let literal = &input.literal;
+
+    let format = literal.value();
+    let pieces = match parse(&format, literal.span()) {
+        Err(e) => return e.to_compile_error().into(),
+        Ok(pieces) => pieces,
+    };
+
+    if let Err(e) = check_args(&input, &pieces) {
+        return e.to_compile_error().into();
+    }
+
+    let format = canonical(&pieces);
+    let symbol = format!(
+        "ufmt_deferred:{}:{:016x}:{}",
+        std::env::var("CARGO_CRATE_NAME").unwrap_or_default(),
+        disambiguator(&format),
+        format,
+    );
+
+    let mut args = vec![];
+    let mut pats = vec![];
+    for (i, arg) in input.args.iter().enumerate() {
+        let pat = mk_ident(i);
+
+        args.push(quote!(&(#arg)));
+        pats.push(quote!(#pat));
+    }
+
+    quote!(match (#(#args),*) {
+        (#(#pats),*) => {
+            #[link_section = "ufmt_deferred"]
+            #[export_name = #symbol]
+            #[used]
+            static SYMBOL: u8 = 0;
+
+            use ufmt::deferred::UnstableDoDeferred as _;
+
+            (#sink).do_deferred(core::ptr::addr_of!(SYMBOL), |s| {
+                #(ufmt::deferred::uEncode::encode(#pats, s)?;)*
+                core::result::Result::Ok(())
+            })
+        }
+    })
+    .into()
+}
+
+// number of `uwrite_deferred!` expansions seen so far, per call site, in the crate being compiled
+static EXPANSIONS: Mutex<Option<HashMap<String, u64>>> = Mutex::new(None);
+
+// makes the symbol name of each `uwrite_deferred!` call unique within its crate, and the same from
+// one build to the next. The expansions of a `macro_rules!` macro share the same file, line and
+// column so they are told apart by how many times that call site has been expanded before
+fn disambiguator(format: &str) -> u64 {
+    let span = proc_macro::Span::call_site();
+    let site = format!(
+        "{}:{}:{}:{}",
+        span.file(),
+        span.line(),
+        span.column(),
+        format
+    );
+
+    let mut expansions = EXPANSIONS.lock().unwrap_or_else(|e| e.into_inner());
+    let count = expansions
+        .get_or_insert_with(HashMap::new)
+        .entry(site.clone())
+        .or_insert(0);
+    let key = format!("{}:{}", site, count);
+    *count += 1;
+
+    // 64-bit FNV-1a; unlike `DefaultHasher` its output is fixed
+    key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+// turns the `pieces` back into a format string; the host decoder only needs to understand this
+// normalized form
+fn canonical(pieces: &[Piece]) -> String {
+    let mut format = String::new();
+    for piece in pieces {
+        match piece {
+            Piece::Str(s) => format.push_str(&s.replace('{', "{{").replace('}', "}}")),
+            Piece::Display => format.push_str("{}"),
+            Piece::Debug { pretty: false } => format.push_str("{:?}"),
+            Piece::Debug { pretty: true } => format.push_str("{:#?}"),
+            Piece::Hex {
+                upper_case,
+                pad_char,
+                pad_length,
+                prefix,
+            } => {
+                format.push_str("{:");
+                if *prefix {
+                    format.push('#');
+                }
+                if *pad_char == b'0' {
+                    format.push('0');
+                }
+                if *pad_length != 0 {
+                    format.push_str(&pad_length.to_string());
+                }
+                format.push(if *upper_case { 'X' } else { 'x' });
+                format.push('}');
+            }
+        }
+    }
+
+    format
+}
+
 struct Input {
     formatter: Expr,
     _comma: Token![,],
@@ -333,6 +820,10 @@ impl Piece<'_> {
     fn is_str(&self) -> bool {
         matches!(self, Piece::Str(_))
     }
+
+    fn is_hex(&self) -> bool {
+        matches!(self, Piece::Hex { .. })
+    }
 }
 
 fn mk_ident(i: usize) -> Ident {
@@ -340,7 +831,7 @@ fn mk_ident(i: usize) -> Ident {
 }
 
 // `}}` -> `}`
-fn unescape(mut literal: &str, span: Span) -> parse::Result<Cow<str>> {
+fn unescape(mut literal: &str, span: Span) -> parse::Result<Cow<'_, str>> {
     if literal.contains('}') {
         let mut buf = String::new();
 
@@ -374,7 +865,7 @@ fn unescape(mut literal: &str, span: Span) -> parse::Result<Cow<str>> {
     }
 }
 
-fn parse(mut literal: &str, span: Span) -> parse::Result<Vec<Piece>> {
+fn parse(mut literal: &str, span: Span) -> parse::Result<Vec<Piece<'_>>> {
     let mut pieces = vec![];
 
     let mut buf = String::new();
@@ -476,7 +967,7 @@ fn split_number(src: &str) -> (&str, usize) {
 }
 
 /// parses the stuff after a `{:` into a [Piece] and the trailing `&str` (what comes after the `}`)
-fn parse_colon(format: &str, span: Span) -> parse::Result<(Piece, &str)> {
+fn parse_colon(format: &str, span: Span) -> parse::Result<(Piece<'_>, &str)> {
     let (format, prefix) = if let Some(tail) = format.strip_prefix('#') {
         (tail, true)
     } else {
@@ -631,6 +1122,22 @@ mod tests {
         assert_eq!(super::unescape("}} ", span).ok(), Some(Cow::Borrowed("} ")));
     }
 
+    #[test]
+    fn canonical() {
+        let span = Span::call_site();
+
+        for format in [
+            "{} and {:?}",
+            "{:#?}",
+            "{{}}",
+            "{:#010X} {:4x} {:#0x} {:x}",
+            "done\n",
+        ] {
+            let pieces = super::parse(format, span).unwrap();
+            assert_eq!(super::canonical(&pieces), format);
+        }
+    }
+
     #[test]
     fn split_number() {
         let (a, b) = crate::split_number("42 card pickup");
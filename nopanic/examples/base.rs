@@ -0,0 +1,24 @@
+#![no_main]
+#![no_std]
+
+use ufmt::{uwrite, Base32, Base64};
+
+use common::W;
+
+#[no_mangle]
+fn _start(bytes: &[u8], wrap: usize) {
+    uwrite!(&mut W, "{}", Base64::new(bytes)).unwrap();
+    uwrite!(
+        &mut W,
+        "{}",
+        Base64::new(bytes).url_safe().padding(false).wrap(wrap)
+    )
+    .unwrap();
+    uwrite!(&mut W, "{}", Base32::new(bytes)).unwrap();
+    uwrite!(
+        &mut W,
+        "{}",
+        Base32::new(bytes).hex().padding(false).wrap(wrap)
+    )
+    .unwrap();
+}
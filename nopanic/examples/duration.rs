@@ -0,0 +1,15 @@
+#![no_main]
+#![no_std]
+
+use core::time::Duration;
+
+use ufmt::{uwrite, FixedUnit, TimeUnit};
+
+use common::W;
+
+#[no_mangle]
+fn _start(secs: u64, nanos: u32) {
+    let d = Duration::new(secs, nanos % 1_000_000_000);
+    uwrite!(&mut W, "{:?}", d).unwrap();
+    uwrite!(&mut W, "{}", FixedUnit::new(d, TimeUnit::Millis)).unwrap();
+}
@@ -0,0 +1,39 @@
+#![no_main]
+#![no_std]
+
+use core::{convert::TryFrom, num::ParseIntError, str::Utf8Error};
+
+use ufmt::{
+    derive::{uDebug, uError},
+    error::Report,
+    uwrite,
+};
+
+use common::W;
+
+#[derive(uDebug, uError)]
+enum Error {
+    #[uerror("invalid UTF-8")]
+    Utf8(#[source] Utf8Error),
+    #[uerror("invalid number at offset {}", offset)]
+    Parse {
+        offset: usize,
+        #[source]
+        source: ParseIntError,
+    },
+}
+
+#[no_mangle]
+fn _start(bytes: &[u8]) {
+    if let Err(e) = core::str::from_utf8(bytes) {
+        uwrite!(&mut W, "{} {:?}", e, e).unwrap();
+        uwrite!(&mut W, "{}", Report::new(Error::Utf8(e))).unwrap();
+    }
+    if let Err(e) = u8::try_from(bytes.len()) {
+        uwrite!(&mut W, "{} {:?}", e, e).unwrap();
+    }
+    if let Ok(Err(source)) = core::str::from_utf8(bytes).map(str::parse::<u8>) {
+        let e = Error::Parse { offset: 0, source };
+        uwrite!(&mut W, "{:?}", Report::new(e)).unwrap();
+    }
+}
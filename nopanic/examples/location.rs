@@ -0,0 +1,13 @@
+#![no_main]
+#![no_std]
+
+use core::panic::Location;
+
+use ufmt::uwrite;
+
+use common::W;
+
+#[no_mangle]
+fn _start() {
+    uwrite!(&mut W, "{}", Location::caller()).unwrap();
+}
@@ -0,0 +1,21 @@
+#![no_main]
+#![no_std]
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+use ufmt::uwrite;
+
+use common::W;
+
+#[no_mangle]
+fn _start(a: [u8; 4], b: [u16; 8], port: u16, scope_id: u32) {
+    uwrite!(&mut W, "{}", IpAddr::V4(Ipv4Addr::from(a))).unwrap();
+    let ip = Ipv6Addr::from(b);
+    uwrite!(&mut W, "{:?}", ip).unwrap();
+    uwrite!(
+        &mut W,
+        "{}",
+        SocketAddr::V6(SocketAddrV6::new(ip, port, 0, scope_id))
+    )
+    .unwrap();
+}
@@ -0,0 +1,33 @@
+#![no_main]
+#![no_std]
+
+use core::{convert::Infallible, panic::PanicInfo, ptr};
+
+use ufmt::{uWrite, uwrite};
+
+// `common` can't be used here: it links `panic-never`, which provides its own panic handler
+struct W;
+
+impl uWrite for W {
+    type Error = Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        s.as_bytes().iter().for_each(|b| unsafe {
+            let _ = ptr::read_volatile(b);
+        });
+
+        Ok(())
+    }
+}
+
+#[no_mangle]
+fn _start() {
+    panic!("boom");
+}
+
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    uwrite!(&mut W, "{}", info).ok();
+
+    loop {}
+}
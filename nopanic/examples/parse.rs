@@ -0,0 +1,24 @@
+#![no_main]
+#![no_std]
+
+use core::num::NonZeroU16;
+
+use ufmt::{uFromStr, uFromStrHex, uwrite};
+
+use common::W;
+
+#[no_mangle]
+fn _start(s: &str) {
+    match i64::from_str(s) {
+        Ok(n) => uwrite!(&mut W, "{}", n).unwrap(),
+        Err(e) => uwrite!(&mut W, "{}", e).unwrap(),
+    }
+
+    if let Ok(n) = NonZeroU16::from_str(s) {
+        uwrite!(&mut W, "{}", n).unwrap();
+    }
+
+    if let Ok(n) = u128::from_str_hex(s) {
+        uwrite!(&mut W, "{:x}", n).unwrap();
+    }
+}
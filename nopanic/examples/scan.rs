@@ -0,0 +1,20 @@
+#![no_main]
+#![no_std]
+
+use ufmt::{uscan, uwrite};
+
+use common::W;
+
+#[no_mangle]
+fn _start(s: &str) {
+    let (mut name, mut value) = ("", 0u32);
+    match uscan!(s, "set {} {:x}", name, value) {
+        Ok(()) => uwrite!(&mut W, "{} = {}", name, value).unwrap(),
+        Err(e) => uwrite!(&mut W, "{:?}", e).unwrap(),
+    }
+
+    let (mut x, mut y) = (0i16, 0u8);
+    if uscan!(s, "{},{}", x, y).is_ok() {
+        uwrite!(&mut W, "{} {}", x, y).unwrap();
+    }
+}
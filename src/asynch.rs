@@ -0,0 +1,147 @@
+use core::str;
+
+use crate::{uAsyncWrite, uDebug, uWrite, Formatter};
+
+// size of the stack buffer that `uwrite_async!` formats into when the caller doesn't pick one
+#[doc(hidden)]
+pub const UNSTABLE_DEFAULT_CAPACITY: usize = 128;
+
+/// The error returned by [`uwrite_async!`](macro.uwrite_async.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsyncWriteError<E> {
+    /// The formatted message does not fit in the stack buffer of `uwrite_async!`; nothing was
+    /// written. Pass a larger `capacity` to `uwrite_async!`
+    Overflow,
+    /// The writer returned an error
+    Write(E),
+}
+
+impl<E> uDebug for AsyncWriteError<E>
+where
+    E: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            AsyncWriteError::Overflow => f.write_str("Overflow"),
+            AsyncWriteError::Write(e) => f.debug_tuple("Write")?.field(e)?.finish(),
+        }
+    }
+}
+
+// Implementation detail of the `uwrite_async!` macro
+//
+// A `uWrite`-r that stores the whole message or reports that it does not fit
+#[doc(hidden)]
+pub struct UnstableBuffer<const N: usize> {
+    len: usize,
+    buf: [u8; N],
+}
+
+// Implementation detail of the `uwrite_async!` macro; the buffer is full
+#[doc(hidden)]
+pub struct UnstableFull;
+
+impl<const N: usize> uWrite for UnstableBuffer<N> {
+    type Error = UnstableFull;
+
+    fn write_str(&mut self, s: &str) -> Result<(), UnstableFull> {
+        let end = self.len + s.len();
+        let dst = self.buf.get_mut(self.len..end).ok_or(UnstableFull)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// Implementation detail of the `uwrite_async!` macro
+#[doc(hidden)]
+#[allow(async_fn_in_trait)]
+pub trait UnstableDoAsync {
+    type Error;
+
+    async fn do_async<const N: usize>(
+        &mut self,
+        f: impl FnOnce(&mut Formatter<'_, UnstableBuffer<N>>) -> Result<(), UnstableFull>,
+    ) -> Result<(), AsyncWriteError<Self::Error>>;
+}
+
+impl<W> UnstableDoAsync for W
+where
+    W: uAsyncWrite + ?Sized,
+{
+    type Error = W::Error;
+
+    // the message is formatted exactly once, before the first `.await`, so the arguments are only
+    // read once even if they are changed (e.g. atomics or `Cell`-s) while the writer is busy
+    async fn do_async<const N: usize>(
+        &mut self,
+        f: impl FnOnce(&mut Formatter<'_, UnstableBuffer<N>>) -> Result<(), UnstableFull>,
+    ) -> Result<(), AsyncWriteError<W::Error>> {
+        let mut buffer = UnstableBuffer {
+            len: 0,
+            buf: [0; N],
+        };
+        f(&mut Formatter::new(&mut buffer)).map_err(|_| AsyncWriteError::Overflow)?;
+
+        // only whole string slices are ever copied into the buffer
+        let s = str::from_utf8(buffer.buf.get(..buffer.len).unwrap_or(&[])).unwrap_or_default();
+        if !s.is_empty() {
+            self.write_str(s).await.map_err(AsyncWriteError::Write)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write formatted data into an asynchronous writer
+///
+/// Like [`uwrite!`](macro.uwrite.html) but the writer must have type `[&mut] impl uAsyncWrite`
+/// and the macro evaluates to a future of `Result<(), AsyncWriteError<_>>`. The `uDisplay` and
+/// `uDebug` implementations are reused as they are: the message is formatted once, synchronously,
+/// into a stack buffer and then handed to the writer with a single `write_str` call.
+///
+/// The buffer is part of the future, and so it's kept alive across the `.await`. It's 128 bytes
+/// long unless a different size is passed as `capacity = N` right after the writer. Messages that
+/// don't fit in the buffer are not written at all; the future resolves to
+/// `AsyncWriteError::Overflow` instead.
+///
+/// ```
+/// use core::convert::Infallible;
+///
+/// use ufmt::{uAsyncWrite, uwrite_async, AsyncWriteError};
+///
+/// struct Uart;
+///
+/// impl uAsyncWrite for Uart {
+///     type Error = Infallible;
+///
+///     async fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+///         // e.g. start a DMA transfer and wait for its completion
+///         Ok(())
+///     }
+/// }
+///
+/// async fn report(uart: &mut Uart, temperature: i16) -> Result<(), AsyncWriteError<Infallible>> {
+///     uwrite_async!(uart, "temperature: {}\n", temperature).await
+/// }
+///
+/// async fn dump(uart: &mut Uart, samples: &[i16; 32]) -> Result<(), AsyncWriteError<Infallible>> {
+///     uwrite_async!(uart, capacity = 512, "{:#?}\n", samples).await
+/// }
+/// ```
+#[macro_export]
+macro_rules! uwrite_async {
+    ($w:expr, capacity = $capacity:expr, $($arg:tt)+) => {{
+        use $crate::UnstableDoAsync as _;
+
+        ($w).do_async::<{ $capacity }>(|f| $crate::uwrite!(f, $($arg)+))
+    }};
+    ($w:expr, $($arg:tt)+) => {{
+        use $crate::UnstableDoAsync as _;
+
+        ($w).do_async::<{ $crate::UNSTABLE_DEFAULT_CAPACITY }>(|f| $crate::uwrite!(f, $($arg)+))
+    }};
+}
@@ -0,0 +1,264 @@
+//! Deferred formatting backend
+//!
+//! [`uwrite_deferred!`] does not format anything on the device. Instead, its format string is
+//! interned as the name of a symbol placed in the `ufmt_deferred` linker section, and only the
+//! index of that symbol plus a compact binary encoding of the arguments is written into a
+//! [`uWriteBytes`]-r. The `ufmt-decoder` crate reconstructs the text on the host from the ELF
+//! file.
+//!
+//! [`uwrite_deferred!`]: ../macro.uwrite_deferred.html
+//! [`uWriteBytes`]: ../trait.uWriteBytes.html
+//!
+//! The frames are binary data so text-only writers, like `String`, can't be used as sinks.
+//!
+//! # Wire format
+//!
+//! Each `uwrite_deferred!` call emits one frame:
+//!
+//! - the offset of the interned string within the `ufmt_deferred` section, as a LEB128 varint
+//! - each argument, as a tag byte followed by its payload
+//!
+//! The high nibble of the tag is the kind of value (see the `TAG_*` constants); the low nibble is
+//! the base-2 logarithm of the size of the value in bytes. Integers are LEB128 varints (signed
+//! integers are zigzag encoded first), `char`-s are varints of their scalar value and strings are
+//! a varint length followed by their UTF-8 bytes.
+//!
+//! # Linking
+//!
+//! The `ufmt_deferred` section must not be loaded into the device memory. With a linker script
+//! this can be achieved with:
+//!
+//! ``` text
+//! SECTIONS
+//! {
+//!   ufmt_deferred 0 (INFO) :
+//!   {
+//!     __start_ufmt_deferred = .;
+//!     KEEP(*(ufmt_deferred));
+//!   }
+//! }
+//! ```
+//!
+//! When the section is not placed by a linker script, GNU ld and LLD define
+//! `__start_ufmt_deferred` on their own.
+//!
+//! # Supported targets
+//!
+//! Only targets that produce ELF object files are supported, e.g. `thumbv7m-none-eabi` or
+//! `x86_64-unknown-linux-gnu`: the backend relies on the ELF section name and on the
+//! `__start_ufmt_deferred` symbol, and the host decoder reads ELF files. Enabling the `deferred`
+//! feature on an Apple (Mach-O), Windows or UEFI (COFF) or WebAssembly target is a compile error.
+//!
+//! Each call gets its own symbol, named after the crate and a hash of the call site (file, line
+//! and column), the format string and, for calls that share a call site, like the expansions of a
+//! `macro_rules!` macro, the order in which they are expanded. None of these change from one build
+//! of the same source to the next so neither do the names.
+
+use core::ptr;
+
+use crate::{uWrite, uWriteBytes};
+
+/// Tag of unsigned integers
+pub const TAG_UNSIGNED: u8 = 0x00;
+/// Tag of signed integers
+pub const TAG_SIGNED: u8 = 0x10;
+/// Tag of `bool`-eans
+pub const TAG_BOOL: u8 = 0x20;
+/// Tag of `char`-s
+pub const TAG_CHAR: u8 = 0x30;
+/// Tag of string slices
+pub const TAG_STR: u8 = 0x40;
+
+/// Values that can be arguments of `uwrite_deferred!`
+#[allow(non_camel_case_types)]
+pub trait uEncode {
+    /// Writes the tag and payload of this value into the `sink`
+    fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: uWriteBytes + ?Sized;
+}
+
+// Implementation detail of the `uwrite_deferred!` macro
+#[doc(hidden)]
+pub trait UnstableDoDeferred {
+    type Sink: uWriteBytes + ?Sized;
+
+    fn do_deferred(
+        &mut self,
+        symbol: *const u8,
+        f: impl FnOnce(&mut Self::Sink) -> Result<(), <Self::Sink as uWrite>::Error>,
+    ) -> Result<(), <Self::Sink as uWrite>::Error>;
+}
+
+impl<S> UnstableDoDeferred for S
+where
+    S: uWriteBytes + ?Sized,
+{
+    type Sink = S;
+
+    #[inline(always)]
+    fn do_deferred(
+        &mut self,
+        symbol: *const u8,
+        f: impl FnOnce(&mut S) -> Result<(), S::Error>,
+    ) -> Result<(), S::Error> {
+        extern "C" {
+            static __start_ufmt_deferred: u8;
+        }
+
+        let start = ptr::addr_of!(__start_ufmt_deferred);
+        varint(self, (symbol as usize).wrapping_sub(start as usize) as u128)?;
+        f(self)
+    }
+}
+
+fn varint<S>(sink: &mut S, mut n: u128) -> Result<(), S::Error>
+where
+    S: uWriteBytes + ?Sized,
+{
+    let mut buf = [0; 19];
+    let mut len = 0;
+    for slot in buf.iter_mut() {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        len += 1;
+
+        if n == 0 {
+            *slot = byte;
+            break;
+        } else {
+            *slot = byte | 0x80;
+        }
+    }
+
+    sink.write_bytes(buf.get(..len).unwrap_or(&buf))
+}
+
+// base-2 logarithm of the size of `T` in bytes
+const fn width<T>() -> u8 {
+    core::mem::size_of::<T>().trailing_zeros() as u8
+}
+
+macro_rules! unsigned {
+    ($($uxx:ty),+) => {
+        $(
+            impl uEncode for $uxx {
+                fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+                where
+                    S: uWriteBytes + ?Sized,
+                {
+                    sink.write_bytes(&[TAG_UNSIGNED | width::<$uxx>()])?;
+                    varint(sink, *self as u128)
+                }
+            }
+        )+
+    }
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! signed {
+    ($($ixx:ty),+) => {
+        $(
+            impl uEncode for $ixx {
+                fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+                where
+                    S: uWriteBytes + ?Sized,
+                {
+                    let n = *self as i128;
+                    sink.write_bytes(&[TAG_SIGNED | width::<$ixx>()])?;
+                    varint(sink, ((n << 1) ^ (n >> 127)) as u128)
+                }
+            }
+        )+
+    }
+}
+
+signed!(i8, i16, i32, i64, i128, isize);
+
+impl uEncode for bool {
+    fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: uWriteBytes + ?Sized,
+    {
+        sink.write_bytes(&[TAG_BOOL, *self as u8])
+    }
+}
+
+impl uEncode for char {
+    fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: uWriteBytes + ?Sized,
+    {
+        sink.write_bytes(&[TAG_CHAR | width::<char>()])?;
+        varint(sink, *self as u128)
+    }
+}
+
+impl uEncode for str {
+    fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: uWriteBytes + ?Sized,
+    {
+        sink.write_bytes(&[TAG_STR])?;
+        varint(sink, self.len() as u128)?;
+        sink.write_bytes(self.as_bytes())
+    }
+}
+
+impl<T> uEncode for &'_ T
+where
+    T: uEncode + ?Sized,
+{
+    #[inline(always)]
+    fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: uWriteBytes + ?Sized,
+    {
+        <T as uEncode>::encode(self, sink)
+    }
+}
+
+impl<T> uEncode for &'_ mut T
+where
+    T: uEncode + ?Sized,
+{
+    #[inline(always)]
+    fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+    where
+        S: uWriteBytes + ?Sized,
+    {
+        <T as uEncode>::encode(self, sink)
+    }
+}
+
+macro_rules! nz {
+    ($($NZ:ident : $inner:ident,)*) => {
+        $(
+            impl uEncode for core::num::$NZ {
+                #[inline(always)]
+                fn encode<S>(&self, sink: &mut S) -> Result<(), S::Error>
+                where
+                    S: uWriteBytes + ?Sized,
+                {
+                    <$inner as uEncode>::encode(&self.get(), sink)
+                }
+            }
+        )*
+    }
+}
+
+nz!(
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroI8: i8,
+    NonZeroI128: i128,
+    NonZeroIsize: isize,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64,
+    NonZeroU8: u8,
+    NonZeroU128: u128,
+    NonZeroUsize: usize,
+);
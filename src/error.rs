@@ -0,0 +1,154 @@
+//! Error reporting without `core::error::Error`
+//!
+//! [`uError`] is the `ufmt` counterpart of `core::error::Error`: an error type that can be
+//! formatted with `uDisplay` / `uDebug` and that may have a lower-level source. As `uDisplay` can't
+//! be turned into a trait object, the source is not returned as a `&dyn Error` but handed to a
+//! [`SourceVisitor`]; this keeps the whole chain free of dynamic dispatch and lets each variant
+//! of an enum have a source of a different type.
+//!
+//! [`Report`] formats an error followed by its chain of sources, one per line.
+//!
+//! ```
+//! use ufmt::{derive::uError, error::Report, uwrite};
+//!
+//! #[derive(ufmt::derive::uDebug, uError)]
+//! enum BusError {
+//!     #[uerror("no acknowledge from address {:#x}", address)]
+//!     Nack { address: u8 },
+//! }
+//!
+//! #[derive(ufmt::derive::uDebug, uError)]
+//! enum SensorError {
+//!     #[uerror("failed to read the temperature")]
+//!     Read(#[source] BusError),
+//!     #[uerror("invalid reading: {}", _0)]
+//!     Invalid(i16),
+//! }
+//!
+//! let error = SensorError::Read(BusError::Nack { address: 0x48 });
+//!
+//! let mut s = String::new();
+//! uwrite!(s, "{}", Report::new(error)).unwrap();
+//! assert_eq!(
+//!     s,
+//!     "error: failed to read the temperature\ncaused by: no acknowledge from address 0x48",
+//! );
+//! ```
+
+use crate::{uDebug, uDisplay, uWrite, Formatter};
+
+/// Just like `core::error::Error`
+#[allow(non_camel_case_types)]
+pub trait uError: uDisplay + uDebug {
+    /// Passes the lower-level source of this error, if there's one, to `visitor` and returns its
+    /// output
+    ///
+    /// The default implementation reports no source.
+    fn source<V>(&self, visitor: V) -> Option<V::Output>
+    where
+        V: SourceVisitor,
+    {
+        let _ = visitor;
+        None
+    }
+}
+
+/// Receives the source of an error from [`uError::source`]
+pub trait SourceVisitor {
+    /// The value returned from `visit`
+    type Output;
+
+    /// Called with the source of the error
+    fn visit<E>(self, source: &E) -> Self::Output
+    where
+        E: uError + ?Sized;
+}
+
+/// Formats an error followed by the chain of its sources
+///
+/// The output looks like this:
+///
+/// ``` text
+/// error: failed to read the temperature
+/// caused by: no acknowledge from address 0x48
+/// ```
+///
+/// `{:?}` produces the same output as `{}`.
+pub struct Report<E> {
+    error: E,
+}
+
+impl<E> Report<E>
+where
+    E: uError,
+{
+    /// Wraps the given `error`
+    pub const fn new(error: E) -> Self {
+        Self { error }
+    }
+
+    /// Returns the wrapped error
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> From<E> for Report<E>
+where
+    E: uError,
+{
+    fn from(error: E) -> Self {
+        Self::new(error)
+    }
+}
+
+// writes every source of an error, one per line
+struct Causes<'f, 'w, W>
+where
+    W: uWrite + ?Sized,
+{
+    f: &'f mut Formatter<'w, W>,
+}
+
+impl<W> SourceVisitor for Causes<'_, '_, W>
+where
+    W: uWrite + ?Sized,
+{
+    type Output = Result<(), W::Error>;
+
+    fn visit<E>(self, source: &E) -> Result<(), W::Error>
+    where
+        E: uError + ?Sized,
+    {
+        self.f.write_str("\ncaused by: ")?;
+        <E as uDisplay>::fmt(source, self.f)?;
+        source.source(Causes { f: self.f }).unwrap_or(Ok(()))
+    }
+}
+
+impl<E> uDisplay for Report<E>
+where
+    E: uError,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("error: ")?;
+        <E as uDisplay>::fmt(&self.error, f)?;
+        self.error.source(Causes { f }).unwrap_or(Ok(()))
+    }
+}
+
+impl<E> uDebug for Report<E>
+where
+    E: uError,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <Self as uDisplay>::fmt(self, f)
+    }
+}
@@ -1,10 +1,23 @@
+#[cfg(feature = "alloc")]
+mod alloc;
 mod array;
+mod atomic;
+mod base;
+mod cell;
 mod core;
+mod error;
+#[cfg(feature = "heapless")]
+mod heapless;
 mod hex;
 mod ixx;
+mod net;
 mod nz;
+mod ops;
+mod panic;
 mod ptr;
 #[cfg(feature = "std")]
 mod std;
+mod time;
 mod tuple;
 mod uxx;
+mod wrapping;
@@ -0,0 +1,211 @@
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::{self, Arc};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    rc::{self, Rc},
+    string::String,
+    vec::Vec,
+};
+
+use crate::{
+    error::{uError, SourceVisitor},
+    uDebug, uDisplay, uWrite, Formatter,
+};
+
+macro_rules! pointer {
+    ($($Pointer:ident,)*) => {
+        $(
+            impl<T> uDebug for $Pointer<T>
+            where
+                T: uDebug + ?Sized,
+            {
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <T as uDebug>::fmt(self, f)
+                }
+            }
+
+            impl<T> uDisplay for $Pointer<T>
+            where
+                T: uDisplay + ?Sized,
+            {
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <T as uDisplay>::fmt(self, f)
+                }
+            }
+
+            impl<T> uError for $Pointer<T>
+            where
+                T: uError + ?Sized,
+            {
+                fn source<V>(&self, visitor: V) -> Option<V::Output>
+                where
+                    V: SourceVisitor,
+                {
+                    T::source(self, visitor)
+                }
+            }
+        )*
+    }
+}
+
+pointer!(Box, Rc,);
+#[cfg(target_has_atomic = "ptr")]
+pointer!(Arc,);
+
+impl<T> uDebug for rc::Weak<T>
+where
+    T: ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("(Weak)")
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> uDebug for sync::Weak<T>
+where
+    T: ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("(Weak)")
+    }
+}
+
+impl<B> uDebug for Cow<'_, B>
+where
+    B: ToOwned + uDebug + ?Sized,
+    B::Owned: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            Cow::Borrowed(b) => <B as uDebug>::fmt(b, f),
+            Cow::Owned(o) => <B::Owned as uDebug>::fmt(o, f),
+        }
+    }
+}
+
+impl<B> uDisplay for Cow<'_, B>
+where
+    B: ToOwned + uDisplay + ?Sized,
+    B::Owned: uDisplay,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            Cow::Borrowed(b) => <B as uDisplay>::fmt(b, f),
+            Cow::Owned(o) => <B::Owned as uDisplay>::fmt(o, f),
+        }
+    }
+}
+
+impl<K, V> uDebug for BTreeMap<K, V>
+where
+    K: uDebug,
+    V: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_map()?.entries(self)?.finish()
+    }
+}
+
+impl<T> uDebug for BTreeSet<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_set()?.entries(self)?.finish()
+    }
+}
+
+// like `core`, this lists the elements in their internal order, not in sorted order
+impl<T> uDebug for BinaryHeap<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_list()?.entries(self)?.finish()
+    }
+}
+
+impl<T> uDebug for LinkedList<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_list()?.entries(self)?.finish()
+    }
+}
+
+impl<T> uDebug for VecDeque<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_list()?.entries(self)?.finish()
+    }
+}
+
+// TODO
+// impl uDebug for String {
+//     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+//     where
+//         W: uWrite + ?Sized,
+//     {
+//         <str as uDebug>::fmt(self, f)
+//     }
+// }
+
+impl uDisplay for String {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <str as uDisplay>::fmt(self, f)
+    }
+}
+
+impl<T> uDebug for Vec<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <[T] as uDebug>::fmt(self, f)
+    }
+}
@@ -0,0 +1,56 @@
+//! Like `core`, these print the value loaded with `Relaxed` ordering
+
+#[allow(unused_imports)]
+use core::sync::atomic::Ordering;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicBool, AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicIsize, AtomicPtr, AtomicUsize};
+
+#[allow(unused_imports)]
+use crate::{uDebug, uWrite, Formatter};
+
+#[allow(unused_macros)]
+macro_rules! atomic {
+    ($($Atomic:ident : $inner:ty,)*) => {
+        $(
+            impl uDebug for $Atomic {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <$inner as uDebug>::fmt(&self.load(Ordering::Relaxed), f)
+                }
+            }
+        )*
+    }
+}
+
+#[cfg(target_has_atomic = "8")]
+atomic!(AtomicBool: bool, AtomicI8: i8, AtomicU8: u8,);
+#[cfg(target_has_atomic = "16")]
+atomic!(AtomicI16: i16, AtomicU16: u16,);
+#[cfg(target_has_atomic = "32")]
+atomic!(AtomicI32: i32, AtomicU32: u32,);
+#[cfg(target_has_atomic = "64")]
+atomic!(AtomicI64: i64, AtomicU64: u64,);
+#[cfg(target_has_atomic = "ptr")]
+atomic!(AtomicIsize: isize, AtomicUsize: usize,);
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> uDebug for AtomicPtr<T> {
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <*mut T as uDebug>::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{uDisplay, uWrite, Base32, Base64, Formatter};
+
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32_HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// stages the encoded (ASCII) text in a small buffer to batch the writes, and wraps lines
+struct Encoder<'f, 'w, W>
+where
+    W: uWrite + ?Sized,
+{
+    fmt: &'f mut Formatter<'w, W>,
+    buf: [u8; 64],
+    len: usize,
+    column: usize,
+    wrap: usize,
+}
+
+impl<'f, 'w, W> Encoder<'f, 'w, W>
+where
+    W: uWrite + ?Sized,
+{
+    fn new(fmt: &'f mut Formatter<'w, W>, wrap: usize) -> Self {
+        Self {
+            fmt,
+            buf: [0; 64],
+            len: 0,
+            column: 0,
+            wrap,
+        }
+    }
+
+    fn push(&mut self, c: u8) -> Result<(), W::Error> {
+        if self.wrap != 0 && self.column == self.wrap {
+            self.put(b'\n')?;
+            self.column = 0;
+        }
+
+        self.column += 1;
+        self.put(c)
+    }
+
+    fn put(&mut self, c: u8) -> Result<(), W::Error> {
+        if self.len == self.buf.len() {
+            self.flush()?;
+        }
+
+        if let Some(slot) = self.buf.get_mut(self.len) {
+            *slot = c;
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), W::Error> {
+        let text = self.buf.get(..self.len).unwrap_or(&[]);
+        self.len = 0;
+        // all the alphabets and the padding are ASCII
+        self.fmt
+            .write_str(unsafe { core::str::from_utf8_unchecked(text) })
+    }
+}
+
+impl uDisplay for Base32<'_> {
+    fn fmt<W>(&self, fmt: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let alphabet = if self.hex { BASE32_HEX } else { BASE32 };
+        let mut encoder = Encoder::new(fmt, self.wrap);
+
+        // 5 bytes become 8 characters of 5 bits each
+        for chunk in self.bytes.chunks(5) {
+            let mut n = 0;
+            for (i, byte) in chunk.iter().enumerate() {
+                n |= u64::from(*byte) << (32 - 8 * i);
+            }
+
+            let chars = (chunk.len() * 8).div_ceil(5);
+            for i in 0..8 {
+                if i < chars {
+                    if let Some(c) = alphabet.get((n >> (35 - 5 * i)) as usize & 31) {
+                        encoder.push(*c)?;
+                    }
+                } else if self.padding {
+                    encoder.push(b'=')?;
+                }
+            }
+        }
+
+        encoder.flush()
+    }
+}
+
+impl uDisplay for Base64<'_> {
+    fn fmt<W>(&self, fmt: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let alphabet = if self.url_safe { BASE64_URL } else { BASE64 };
+        let mut encoder = Encoder::new(fmt, self.wrap);
+
+        // 3 bytes become 4 characters of 6 bits each
+        for chunk in self.bytes.chunks(3) {
+            let mut n = 0;
+            for (i, byte) in chunk.iter().enumerate() {
+                n |= u32::from(*byte) << (16 - 8 * i);
+            }
+
+            let chars = chunk.len() + 1;
+            for i in 0..4 {
+                if i < chars {
+                    if let Some(c) = alphabet.get((n >> (18 - 6 * i)) as usize & 63) {
+                        encoder.push(*c)?;
+                    }
+                } else if self.padding {
+                    encoder.push(b'=')?;
+                }
+            }
+        }
+
+        encoder.flush()
+    }
+}
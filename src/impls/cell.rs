@@ -0,0 +1,50 @@
+use core::cell::{Cell, RefCell};
+
+use crate::{uDebug, uWrite, Formatter};
+
+impl<T> uDebug for Cell<T>
+where
+    T: Copy + uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_struct("Cell")?
+            .field("value", &self.get())?
+            .finish()
+    }
+}
+
+impl<T> uDebug for RefCell<T>
+where
+    T: uDebug + ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        // stands in for the value while it's mutably borrowed
+        struct Borrowed;
+
+        impl uDebug for Borrowed {
+            fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: uWrite + ?Sized,
+            {
+                f.write_str("<borrowed>")
+            }
+        }
+
+        match self.try_borrow() {
+            Ok(value) => f
+                .debug_struct("RefCell")?
+                .field("value", &&*value)?
+                .finish(),
+            Err(_) => f
+                .debug_struct("RefCell")?
+                .field("value", &Borrowed)?
+                .finish(),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{uDebug, uDisplay, uWrite, Formatter};
+use core::{convert::Infallible, mem::ManuallyDrop, ops::Deref, pin::Pin};
+
+use crate::{uDebug, uDebugMaxLen, uDisplay, uDisplayMaxLen, uWrite, Formatter};
 
 impl uDebug for bool {
     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
@@ -23,6 +25,14 @@ impl uDisplay for bool {
     }
 }
 
+impl uDisplayMaxLen for bool {
+    const MAX_LEN: usize = 5;
+}
+
+impl uDebugMaxLen for bool {
+    const MAX_LEN: usize = 5;
+}
+
 // FIXME this (`escape_debug`) contains a panicking branch
 // impl uDebug for char {
 //     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
@@ -47,6 +57,10 @@ impl uDisplay for char {
     }
 }
 
+impl uDisplayMaxLen for char {
+    const MAX_LEN: usize = 4;
+}
+
 impl<T> uDebug for [T]
 where
     T: uDebug,
@@ -154,6 +168,34 @@ where
     }
 }
 
+impl<T> uDisplayMaxLen for &'_ T
+where
+    T: uDisplayMaxLen + ?Sized,
+{
+    const MAX_LEN: usize = T::MAX_LEN;
+}
+
+impl<T> uDisplayMaxLen for &'_ mut T
+where
+    T: uDisplayMaxLen + ?Sized,
+{
+    const MAX_LEN: usize = T::MAX_LEN;
+}
+
+impl<T> uDebugMaxLen for &'_ T
+where
+    T: uDebugMaxLen + ?Sized,
+{
+    const MAX_LEN: usize = T::MAX_LEN;
+}
+
+impl<T> uDebugMaxLen for &'_ mut T
+where
+    T: uDebugMaxLen + ?Sized,
+{
+    const MAX_LEN: usize = T::MAX_LEN;
+}
+
 impl<T> uDebug for Option<T>
 where
     T: uDebug,
@@ -184,3 +226,128 @@ where
         }
     }
 }
+
+impl<T> uDebugMaxLen for Option<T>
+where
+    T: uDebugMaxLen,
+{
+    const MAX_LEN: usize = crate::unstable_max("None".len(), "Some()".len() + T::MAX_LEN);
+}
+
+impl<T, E> uDebugMaxLen for Result<T, E>
+where
+    T: uDebugMaxLen,
+    E: uDebugMaxLen,
+{
+    const MAX_LEN: usize =
+        crate::unstable_max("Ok()".len() + T::MAX_LEN, "Err()".len() + E::MAX_LEN);
+}
+
+impl uDebug for core::cmp::Ordering {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(match self {
+            core::cmp::Ordering::Less => "Less",
+            core::cmp::Ordering::Equal => "Equal",
+            core::cmp::Ordering::Greater => "Greater",
+        })
+    }
+}
+
+impl<T> uDebug for core::cmp::Reverse<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_tuple("Reverse")?.field(&self.0)?.finish()
+    }
+}
+
+impl<T> uDebug for core::marker::PhantomData<T>
+where
+    T: ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("PhantomData<")?;
+        f.write_str(core::any::type_name::<T>())?;
+        f.write_str(">")
+    }
+}
+
+impl uDebug for Infallible {
+    fn fmt<W>(&self, _: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match *self {}
+    }
+}
+
+impl uDisplay for Infallible {
+    fn fmt<W>(&self, _: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match *self {}
+    }
+}
+
+impl uDisplayMaxLen for Infallible {
+    const MAX_LEN: usize = 0;
+}
+
+impl uDebugMaxLen for Infallible {
+    const MAX_LEN: usize = 0;
+}
+
+// NOTE recent versions of `std` also print an internal `MaybeDangling` wrapper around the value;
+// we keep the documented `ManuallyDrop { value: .. }` form
+impl<T> uDebug for ManuallyDrop<T>
+where
+    T: uDebug + ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_struct("ManuallyDrop")?
+            .field("value", &&**self)?
+            .finish()
+    }
+}
+
+impl<P> uDebug for Pin<P>
+where
+    P: Deref,
+    P::Target: uDebug,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <P::Target as uDebug>::fmt(self, f)
+    }
+}
+
+impl<P> uDisplay for Pin<P>
+where
+    P: Deref,
+    P::Target: uDisplay,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <P::Target as uDisplay>::fmt(self, f)
+    }
+}
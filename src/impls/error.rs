@@ -0,0 +1,218 @@
+//! The messages match the `core::fmt::Display` implementations
+
+use core::{
+    alloc::LayoutError,
+    array::TryFromSliceError,
+    char::CharTryFromError,
+    convert::Infallible,
+    num::{IntErrorKind, TryFromIntError},
+    str::{ParseBoolError, Utf8Error},
+};
+
+use crate::{
+    error::{uError, SourceVisitor},
+    uDebug, uDisplay, uWrite, Formatter, ParseIntError, ScanError,
+};
+
+impl<E> uError for &'_ E
+where
+    E: uError + ?Sized,
+{
+    #[inline(always)]
+    fn source<V>(&self, visitor: V) -> Option<V::Output>
+    where
+        V: SourceVisitor,
+    {
+        E::source(self, visitor)
+    }
+}
+
+impl<E> uError for &'_ mut E
+where
+    E: uError + ?Sized,
+{
+    #[inline(always)]
+    fn source<V>(&self, visitor: V) -> Option<V::Output>
+    where
+        V: SourceVisitor,
+    {
+        E::source(self, visitor)
+    }
+}
+
+// these have no source
+impl uError for CharTryFromError {}
+impl uError for core::num::ParseIntError {}
+impl uError for Infallible {}
+impl uError for LayoutError {}
+impl uError for ParseBoolError {}
+impl uError for ParseIntError {}
+impl uError for ScanError {}
+impl uError for TryFromIntError {}
+impl uError for TryFromSliceError {}
+impl uError for Utf8Error {}
+
+// `IntErrorKind` is non-exhaustive; kinds added in the future map to `None`
+fn kind(kind: &IntErrorKind) -> Option<ParseIntError> {
+    Some(match kind {
+        IntErrorKind::Empty => ParseIntError::Empty,
+        IntErrorKind::InvalidDigit => ParseIntError::InvalidDigit,
+        IntErrorKind::PosOverflow => ParseIntError::PosOverflow,
+        IntErrorKind::NegOverflow => ParseIntError::NegOverflow,
+        IntErrorKind::Zero => ParseIntError::Zero,
+        _ => return None,
+    })
+}
+
+impl uDebug for IntErrorKind {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match kind(self) {
+            Some(kind) => <ParseIntError as uDebug>::fmt(&kind, f),
+            None => f.write_str("Unknown"),
+        }
+    }
+}
+
+impl uDebug for core::num::ParseIntError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_struct("ParseIntError")?
+            .field("kind", self.kind())?
+            .finish()
+    }
+}
+
+impl uDisplay for core::num::ParseIntError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match kind(self.kind()) {
+            Some(kind) => <ParseIntError as uDisplay>::fmt(&kind, f),
+            None => f.write_str("invalid integer"),
+        }
+    }
+}
+
+impl uDebug for TryFromIntError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_tuple("TryFromIntError")?.field(&())?.finish()
+    }
+}
+
+impl uDisplay for TryFromIntError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("out of range integral type conversion attempted")
+    }
+}
+
+impl uDebug for Utf8Error {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_struct("Utf8Error")?
+            .field("valid_up_to", &self.valid_up_to())?
+            .field("error_len", &self.error_len())?
+            .finish()
+    }
+}
+
+impl uDisplay for Utf8Error {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        if let Some(len) = self.error_len() {
+            f.write_str("invalid utf-8 sequence of ")?;
+            <usize as uDisplay>::fmt(&len, f)?;
+            f.write_str(" bytes from index ")?;
+        } else {
+            f.write_str("incomplete utf-8 byte sequence from index ")?;
+        }
+        <usize as uDisplay>::fmt(&self.valid_up_to(), f)
+    }
+}
+
+impl uDebug for CharTryFromError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_tuple("CharTryFromError")?.field(&())?.finish()
+    }
+}
+
+impl uDisplay for CharTryFromError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("converted integer out of range for `char`")
+    }
+}
+
+impl uDebug for ParseBoolError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("ParseBoolError")
+    }
+}
+
+impl uDisplay for ParseBoolError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("provided string was not `true` or `false`")
+    }
+}
+
+impl uDebug for TryFromSliceError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_tuple("TryFromSliceError")?.field(&())?.finish()
+    }
+}
+
+impl uDisplay for TryFromSliceError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("could not convert slice to array")
+    }
+}
+
+impl uDebug for LayoutError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("LayoutError")
+    }
+}
+
+impl uDisplay for LayoutError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("invalid parameters to Layout::from_size_align")
+    }
+}
@@ -0,0 +1,88 @@
+use hash32::{BuildHasher, Hash};
+use heapless::{spsc::Queue, Deque, IndexMap, LinearMap, String, Vec};
+
+use crate::{uDebug, uDebugMaxLen, uDisplay, uDisplayMaxLen, uWrite, Formatter};
+
+impl<T, const N: usize> uDebug for Deque<T, N>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_list()?.entries(self)?.finish()
+    }
+}
+
+impl<K, V, S, const N: usize> uDebug for IndexMap<K, V, S, N>
+where
+    K: Eq + Hash + uDebug,
+    V: uDebug,
+    S: BuildHasher,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_map()?.entries(self.iter())?.finish()
+    }
+}
+
+impl<K, V, const N: usize> uDebug for LinearMap<K, V, N>
+where
+    K: Eq + uDebug,
+    V: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_map()?.entries(self.iter())?.finish()
+    }
+}
+
+impl<T, const N: usize> uDebug for Queue<T, N>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_list()?.entries(self)?.finish()
+    }
+}
+
+// TODO uDebug, once `str` implements it
+impl<const N: usize> uDisplay for String<N> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <str as uDisplay>::fmt(self, f)
+    }
+}
+
+impl<const N: usize> uDisplayMaxLen for String<N> {
+    const MAX_LEN: usize = N;
+}
+
+impl<T, const N: usize> uDebug for Vec<T, N>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <[T] as uDebug>::fmt(self, f)
+    }
+}
+
+impl<T, const N: usize> uDebugMaxLen for Vec<T, N>
+where
+    T: uDebugMaxLen,
+{
+    const MAX_LEN: usize = <[T; N] as uDebugMaxLen>::MAX_LEN;
+}
@@ -1,4 +1,4 @@
-use crate::{uDisplayHex, uWrite, Formatter, HexOptions};
+use crate::{uDisplay, uDisplayHex, uWrite, Formatter, HexDump, HexOptions};
 
 macro_rules! hex_format {
     ($buf:expr, $val:expr, $options:expr) => {{
@@ -72,3 +72,128 @@ fn hex_digit(val: u8, upper_case: bool) -> u8 {
         (if upper_case { b'A' } else { b'a' }) + (val - 10)
     }
 }
+
+// writes each byte as two digits; the digits are staged in a small buffer to batch the writes
+fn hex_bytes<W>(fmt: &mut Formatter<'_, W>, bytes: &[u8], upper_case: bool) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    let mut buf = [0; 32];
+    for chunk in bytes.chunks(buf.len() / 2) {
+        let mut len = 0;
+        for (byte, digits) in chunk.iter().zip(buf.chunks_exact_mut(2)) {
+            if let [hi, lo] = digits {
+                *hi = hex_digit(byte >> 4, upper_case);
+                *lo = hex_digit(byte & 0xf, upper_case);
+            }
+            len += 2;
+        }
+
+        fmt.write_str(unsafe { core::str::from_utf8_unchecked(buf.get(..len).unwrap_or(&[])) })?;
+    }
+
+    Ok(())
+}
+
+impl uDisplayHex for [u8] {
+    fn fmt_hex<W>(&self, fmt: &mut Formatter<'_, W>, options: HexOptions) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        options.with_payload(fmt, 2 * self.len(), |fmt| {
+            hex_bytes(fmt, self, options.upper_case)
+        })
+    }
+}
+
+impl<const N: usize> uDisplayHex for [u8; N] {
+    #[inline(always)]
+    fn fmt_hex<W>(&self, fmt: &mut Formatter<'_, W>, options: HexOptions) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <[u8] as uDisplayHex>::fmt_hex(self, fmt, options)
+    }
+}
+
+impl<T> uDisplayHex for &'_ T
+where
+    T: uDisplayHex + ?Sized,
+{
+    #[inline(always)]
+    fn fmt_hex<W>(&self, fmt: &mut Formatter<'_, W>, options: HexOptions) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <T as uDisplayHex>::fmt_hex(self, fmt, options)
+    }
+}
+
+impl<T> uDisplayHex for &'_ mut T
+where
+    T: uDisplayHex + ?Sized,
+{
+    #[inline(always)]
+    fn fmt_hex<W>(&self, fmt: &mut Formatter<'_, W>, options: HexOptions) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <T as uDisplayHex>::fmt_hex(self, fmt, options)
+    }
+}
+
+impl uDisplay for HexDump<'_> {
+    fn fmt<W>(&self, fmt: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let mut offset = 0;
+        while let Some(rest) = self.bytes.get(offset..) {
+            if rest.is_empty() {
+                break;
+            }
+            let line = rest.get(..self.width).unwrap_or(rest);
+
+            if offset != 0 {
+                fmt.write_char('\n')?;
+            }
+
+            <usize as uDisplayHex>::fmt_hex(
+                &offset,
+                fmt,
+                HexOptions {
+                    upper_case: false,
+                    pad_char: b'0',
+                    pad_length: 8,
+                    ox_prefix: false,
+                },
+            )?;
+            fmt.write_str(": ")?;
+
+            // the hex column is padded so that the ASCII gutters of all lines are aligned
+            for i in 0..self.width {
+                match line.get(i) {
+                    Some(byte) => hex_bytes(fmt, core::slice::from_ref(byte), false)?,
+                    None => fmt.write_str("  ")?,
+                }
+
+                if self.group != 0 && (i + 1) % self.group == 0 && i + 1 != self.width {
+                    fmt.write_char(' ')?;
+                }
+            }
+            fmt.write_str("  ")?;
+
+            for byte in line {
+                fmt.write_char(if (0x20..0x7f).contains(byte) {
+                    char::from(*byte)
+                } else {
+                    '.'
+                })?;
+            }
+
+            offset += line.len();
+        }
+
+        Ok(())
+    }
+}
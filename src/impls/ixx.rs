@@ -1,6 +1,6 @@
 use core::{mem::MaybeUninit, slice, str};
 
-use crate::{uDebug, uDisplay, uWrite, Formatter};
+use crate::{uDebug, uDebugMaxLen, uDisplay, uDisplayMaxLen, uWrite, Formatter};
 
 macro_rules! ixx {
     ($uxx:ty, $n:expr, $buf:expr) => {{
@@ -228,3 +228,34 @@ impl uDisplay for isize {
         <i64 as uDisplay>::fmt(&(*self as i64), f)
     }
 }
+
+macro_rules! max_len {
+    ($($ixx:ty: $len:expr),+) => {
+        $(
+            impl uDisplayMaxLen for $ixx {
+                const MAX_LEN: usize = $len;
+            }
+
+            impl uDebugMaxLen for $ixx {
+                const MAX_LEN: usize = $len;
+            }
+        )+
+    }
+}
+
+max_len!(i8: 4, i16: 6, i32: 11, i64: 20, i128: 40);
+
+impl uDisplayMaxLen for isize {
+    #[cfg(target_pointer_width = "16")]
+    const MAX_LEN: usize = <i16 as uDisplayMaxLen>::MAX_LEN;
+
+    #[cfg(target_pointer_width = "32")]
+    const MAX_LEN: usize = <i32 as uDisplayMaxLen>::MAX_LEN;
+
+    #[cfg(target_pointer_width = "64")]
+    const MAX_LEN: usize = <i64 as uDisplayMaxLen>::MAX_LEN;
+}
+
+impl uDebugMaxLen for isize {
+    const MAX_LEN: usize = <isize as uDisplayMaxLen>::MAX_LEN;
+}
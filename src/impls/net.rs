@@ -0,0 +1,166 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::{uDebug, uDisplay, uDisplayHex, uWrite, Formatter, HexOptions};
+
+// like in `core::fmt` the `Debug` representation of these types is their text form
+macro_rules! debug_as_display {
+    ($($ty:ty),+) => {
+        $(
+            impl uDebug for $ty {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <$ty as uDisplay>::fmt(self, f)
+                }
+            }
+        )+
+    }
+}
+
+debug_as_display!(
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+    SocketAddr,
+    SocketAddrV4,
+    SocketAddrV6
+);
+
+impl uDisplay for Ipv4Addr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let [a, b, c, d] = self.octets();
+        <u8 as uDisplay>::fmt(&a, f)?;
+        f.write_char('.')?;
+        <u8 as uDisplay>::fmt(&b, f)?;
+        f.write_char('.')?;
+        <u8 as uDisplay>::fmt(&c, f)?;
+        f.write_char('.')?;
+        <u8 as uDisplay>::fmt(&d, f)
+    }
+}
+
+// writes the segments, in lowercase hex, separated by colons
+fn fmt_segments<W>(f: &mut Formatter<'_, W>, segments: &[u16]) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    for (i, segment) in segments.iter().enumerate() {
+        if i != 0 {
+            f.write_char(':')?;
+        }
+
+        <u16 as uDisplayHex>::fmt_hex(
+            segment,
+            f,
+            HexOptions {
+                upper_case: false,
+                pad_char: b' ',
+                pad_length: 0,
+                ox_prefix: false,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+// same text form as `core::fmt`: IPv4-mapped addresses use the dotted notation for their last 32
+// bits and the longest (leftmost, on ties) run of two or more zero segments is compressed to `::`
+impl uDisplay for Ipv6Addr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        if let Some(ipv4) = self.to_ipv4_mapped() {
+            f.write_str("::ffff:")?;
+            return <Ipv4Addr as uDisplay>::fmt(&ipv4, f);
+        }
+
+        let segments = self.segments();
+
+        // (start, len)
+        let mut longest = (0, 0);
+        let mut current = (0, 0);
+        for (i, segment) in segments.iter().enumerate() {
+            if *segment == 0 {
+                if current.1 == 0 {
+                    current.0 = i;
+                }
+                current.1 += 1;
+
+                if current.1 > longest.1 {
+                    longest = current;
+                }
+            } else {
+                current = (0, 0);
+            }
+        }
+
+        let (start, len) = longest;
+        match (segments.get(..start), segments.get(start + len..)) {
+            (Some(head), Some(tail)) if len > 1 => {
+                fmt_segments(f, head)?;
+                f.write_str("::")?;
+                fmt_segments(f, tail)
+            }
+            _ => fmt_segments(f, &segments),
+        }
+    }
+}
+
+impl uDisplay for IpAddr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            IpAddr::V4(ip) => <Ipv4Addr as uDisplay>::fmt(ip, f),
+            IpAddr::V6(ip) => <Ipv6Addr as uDisplay>::fmt(ip, f),
+        }
+    }
+}
+
+impl uDisplay for SocketAddrV4 {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <Ipv4Addr as uDisplay>::fmt(self.ip(), f)?;
+        f.write_char(':')?;
+        <u16 as uDisplay>::fmt(&self.port(), f)
+    }
+}
+
+// `[ip]:port`, or `[ip%scope_id]:port` if the scope ID is not zero; the flow info is not shown
+impl uDisplay for SocketAddrV6 {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_char('[')?;
+        <Ipv6Addr as uDisplay>::fmt(self.ip(), f)?;
+        if self.scope_id() != 0 {
+            f.write_char('%')?;
+            <u32 as uDisplay>::fmt(&self.scope_id(), f)?;
+        }
+        f.write_str("]:")?;
+        <u16 as uDisplay>::fmt(&self.port(), f)
+    }
+}
+
+impl uDisplay for SocketAddr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            SocketAddr::V4(addr) => <SocketAddrV4 as uDisplay>::fmt(addr, f),
+            SocketAddr::V6(addr) => <SocketAddrV6 as uDisplay>::fmt(addr, f),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use core::num::{
     NonZeroU64, NonZeroU8, NonZeroUsize,
 };
 
-use crate::{uDebug, uDisplay, uWrite, Formatter};
+use crate::{uDebug, uDebugMaxLen, uDisplay, uDisplayMaxLen, uWrite, Formatter};
 
 macro_rules! nz {
     ($($NZ:ident : $inner:ident,)*) => {
@@ -27,6 +27,14 @@ macro_rules! nz {
                     <$inner as uDisplay>::fmt(&self.get(), f)
                 }
             }
+
+            impl uDisplayMaxLen for $NZ {
+                const MAX_LEN: usize = <$inner as uDisplayMaxLen>::MAX_LEN;
+            }
+
+            impl uDebugMaxLen for $NZ {
+                const MAX_LEN: usize = <$inner as uDebugMaxLen>::MAX_LEN;
+            }
         )*
     }
 }
@@ -0,0 +1,86 @@
+use core::ops::{
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+
+use crate::{uDebug, uWrite, Formatter};
+
+impl<Idx> uDebug for Range<Idx>
+where
+    Idx: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.start.fmt(f)?;
+        f.write_str("..")?;
+        self.end.fmt(f)
+    }
+}
+
+// NOTE the `exhausted` flag is private but `end_bound` is `Excluded` exactly when it's set
+impl<Idx> uDebug for RangeInclusive<Idx>
+where
+    Idx: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.start().fmt(f)?;
+        f.write_str("..=")?;
+        self.end().fmt(f)?;
+        if let Bound::Excluded(_) = self.end_bound() {
+            f.write_str(" (exhausted)")?;
+        }
+        Ok(())
+    }
+}
+
+impl<Idx> uDebug for RangeFrom<Idx>
+where
+    Idx: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.start.fmt(f)?;
+        f.write_str("..")
+    }
+}
+
+impl uDebug for RangeFull {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("..")
+    }
+}
+
+impl<Idx> uDebug for RangeTo<Idx>
+where
+    Idx: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("..")?;
+        self.end.fmt(f)
+    }
+}
+
+impl<Idx> uDebug for RangeToInclusive<Idx>
+where
+    Idx: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("..=")?;
+        self.end.fmt(f)
+    }
+}
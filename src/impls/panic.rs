@@ -0,0 +1,37 @@
+use core::panic::{Location, PanicInfo};
+
+use crate::{uDisplay, uWrite, Formatter};
+
+impl uDisplay for Location<'_> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(self.file())?;
+        f.write_str(":")?;
+        <u32 as uDisplay>::fmt(&self.line(), f)?;
+        f.write_str(":")?;
+        <u32 as uDisplay>::fmt(&self.column(), f)
+    }
+}
+
+// NOTE same layout as `core` but the message is only included when it's a string literal; the
+// `format_args!` form can't be rendered without `core::fmt`
+impl uDisplay for PanicInfo<'_> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("panicked")?;
+        if let Some(location) = self.location() {
+            f.write_str(" at ")?;
+            <Location<'_> as uDisplay>::fmt(location, f)?;
+        }
+        f.write_str(":")?;
+        if let Some(message) = self.message().as_str() {
+            f.write_str("\n")?;
+            f.write_str(message)?;
+        }
+        Ok(())
+    }
+}
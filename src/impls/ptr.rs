@@ -1,4 +1,4 @@
-use core::{mem::MaybeUninit, slice, str};
+use core::{mem::MaybeUninit, ptr::NonNull, slice, str};
 
 use crate::{uDebug, uWrite, Formatter};
 
@@ -79,3 +79,13 @@ impl<T> uDebug for *mut T {
         (*self as *const T).fmt(f)
     }
 }
+
+impl<T> uDebug for NonNull<T> {
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        (self.as_ptr() as *const T).fmt(f)
+    }
+}
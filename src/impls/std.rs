@@ -1,56 +1,10 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    panic::{Location, PanicHookInfo},
+};
 
 use crate::{uDebug, uDisplay, uWrite, Formatter};
 
-impl<T> uDebug for Box<T>
-where
-    T: uDebug,
-{
-    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
-    where
-        W: uWrite + ?Sized,
-    {
-        <T as uDebug>::fmt(self, f)
-    }
-}
-
-impl<T> uDisplay for Box<T>
-where
-    T: uDisplay,
-{
-    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
-    where
-        W: uWrite + ?Sized,
-    {
-        <T as uDisplay>::fmt(self, f)
-    }
-}
-
-impl<K, V> uDebug for BTreeMap<K, V>
-where
-    K: uDebug,
-    V: uDebug,
-{
-    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
-    where
-        W: uWrite + ?Sized,
-    {
-        f.debug_map()?.entries(self)?.finish()
-    }
-}
-
-impl<T> uDebug for BTreeSet<T>
-where
-    T: uDebug,
-{
-    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
-    where
-        W: uWrite + ?Sized,
-    {
-        f.debug_set()?.entries(self)?.finish()
-    }
-}
-
 impl<K, V> uDebug for HashMap<K, V>
 where
     K: uDebug,
@@ -76,33 +30,27 @@ where
     }
 }
 
-// TODO
-// impl uDebug for String {
-//     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
-//     where
-//         W: uWrite + ?Sized,
-//     {
-//         <str as uDebug>::fmt(self, f)
-//     }
-// }
-
-impl uDisplay for String {
+// NOTE same layout as `std`; `panic!` payloads are either a `&'static str` or a `String`
+impl uDisplay for PanicHookInfo<'_> {
     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
     where
         W: uWrite + ?Sized,
     {
-        <str as uDisplay>::fmt(self, f)
-    }
-}
+        f.write_str("panicked")?;
+        if let Some(location) = self.location() {
+            f.write_str(" at ")?;
+            <Location<'_> as uDisplay>::fmt(location, f)?;
+        }
 
-impl<T> uDebug for Vec<T>
-where
-    T: uDebug,
-{
-    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
-    where
-        W: uWrite + ?Sized,
-    {
-        <[T] as uDebug>::fmt(self, f)
+        let payload = self.payload();
+        if let Some(message) = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(|s| s.as_str()))
+        {
+            f.write_str(":\n")?;
+            f.write_str(message)?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,72 @@
+use core::time::Duration;
+
+use crate::{uDebug, uDisplay, uWrite, FixedUnit, Formatter, TimeUnit};
+
+// writes `integer.fraction` followed by `suffix`; `fraction` has `digits` decimal digits (at most 9)
+// and its trailing zeros, as well as the dot if it's zero, are omitted
+fn fmt_decimal<W, I>(
+    f: &mut Formatter<'_, W>,
+    integer: I,
+    mut fraction: u32,
+    digits: usize,
+    suffix: &str,
+) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+    I: uDisplay,
+{
+    integer.fmt(f)?;
+
+    let mut buf = [b'0'; 9];
+    for slot in buf.iter_mut().take(digits).rev() {
+        *slot = b'0' + (fraction % 10) as u8;
+        fraction /= 10;
+    }
+
+    let mut len = digits;
+    while len > 0 && buf.get(len - 1) == Some(&b'0') {
+        len -= 1;
+    }
+
+    if let Some(fraction @ [_, ..]) = buf.get(..len) {
+        f.write_char('.')?;
+        f.write_str(unsafe { core::str::from_utf8_unchecked(fraction) })?;
+    }
+
+    f.write_str(suffix)
+}
+
+// same output as `core::fmt::Debug`: the largest unit in which the integer part is not zero
+impl uDebug for Duration {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let nanos = self.subsec_nanos();
+        if self.as_secs() > 0 {
+            fmt_decimal(f, self.as_secs(), nanos, 9, "s")
+        } else if nanos >= 1_000_000 {
+            fmt_decimal(f, nanos / 1_000_000, nanos % 1_000_000, 6, "ms")
+        } else if nanos >= 1_000 {
+            fmt_decimal(f, nanos / 1_000, nanos % 1_000, 3, "µs")
+        } else {
+            fmt_decimal(f, nanos, 0, 0, "ns")
+        }
+    }
+}
+
+impl uDisplay for FixedUnit {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let d = &self.duration;
+        let nanos = d.subsec_nanos();
+        match self.unit {
+            TimeUnit::Seconds => fmt_decimal(f, d.as_secs(), nanos, 9, "s"),
+            TimeUnit::Millis => fmt_decimal(f, d.as_millis(), nanos % 1_000_000, 6, "ms"),
+            TimeUnit::Micros => fmt_decimal(f, d.as_micros(), nanos % 1_000, 3, "µs"),
+            TimeUnit::Nanos => fmt_decimal(f, d.as_nanos(), 0, 0, "ns"),
+        }
+    }
+}
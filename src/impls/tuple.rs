@@ -1,4 +1,4 @@
-use crate::{uDebug, uWrite, Formatter};
+use crate::{uDebug, uDebugMaxLen, uWrite, Formatter};
 
 macro_rules! tuple {
     ($($T:ident),*; $($i:tt),*) => {
@@ -14,6 +14,14 @@ macro_rules! tuple {
             }
         }
 
+        impl<$($T,)*> uDebugMaxLen for ($($T,)*)
+        where
+            $($T: uDebugMaxLen,)*
+        {
+            // "(" + A + ", " + B + .. + ")"; one-element tuples have a trailing comma
+            const MAX_LEN: usize = 2 $(+ $T::MAX_LEN + 2)* - 2 + if [$($i),*].len() == 1 { 1 } else { 0 };
+        }
+
     }
 }
 
@@ -26,6 +34,10 @@ impl uDebug for () {
     }
 }
 
+impl uDebugMaxLen for () {
+    const MAX_LEN: usize = 2;
+}
+
 tuple!(A; 0);
 tuple!(A, B; 0, 1);
 tuple!(A, B, C; 0, 1, 2);
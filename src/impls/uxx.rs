@@ -1,6 +1,6 @@
 use core::{mem::MaybeUninit, slice, str};
 
-use crate::{uDebug, uDisplay, uWrite, Formatter};
+use crate::{uDebug, uDebugMaxLen, uDisplay, uDisplayMaxLen, uWrite, Formatter};
 
 macro_rules! uxx {
     ($n:expr, $buf:expr) => {{
@@ -214,3 +214,34 @@ impl uDisplay for usize {
         <u64 as uDisplay>::fmt(&(*self as u64), f)
     }
 }
+
+macro_rules! max_len {
+    ($($uxx:ty: $len:expr),+) => {
+        $(
+            impl uDisplayMaxLen for $uxx {
+                const MAX_LEN: usize = $len;
+            }
+
+            impl uDebugMaxLen for $uxx {
+                const MAX_LEN: usize = $len;
+            }
+        )+
+    }
+}
+
+max_len!(u8: 3, u16: 5, u32: 10, u64: 20, u128: 39);
+
+impl uDisplayMaxLen for usize {
+    #[cfg(target_pointer_width = "16")]
+    const MAX_LEN: usize = <u16 as uDisplayMaxLen>::MAX_LEN;
+
+    #[cfg(target_pointer_width = "32")]
+    const MAX_LEN: usize = <u32 as uDisplayMaxLen>::MAX_LEN;
+
+    #[cfg(target_pointer_width = "64")]
+    const MAX_LEN: usize = <u64 as uDisplayMaxLen>::MAX_LEN;
+}
+
+impl uDebugMaxLen for usize {
+    const MAX_LEN: usize = <usize as uDisplayMaxLen>::MAX_LEN;
+}
@@ -0,0 +1,51 @@
+use core::num::{Saturating, Wrapping};
+
+use crate::{uDebug, uDebugMaxLen, uDisplay, uDisplayMaxLen, uWrite, Formatter};
+
+macro_rules! transparent {
+    ($($Wrapper:ident,)*) => {
+        $(
+            impl<T> uDebug for $Wrapper<T>
+            where
+                T: uDebug,
+            {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <T as uDebug>::fmt(&self.0, f)
+                }
+            }
+
+            impl<T> uDisplay for $Wrapper<T>
+            where
+                T: uDisplay,
+            {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <T as uDisplay>::fmt(&self.0, f)
+                }
+            }
+
+            impl<T> uDisplayMaxLen for $Wrapper<T>
+            where
+                T: uDisplayMaxLen,
+            {
+                const MAX_LEN: usize = <T as uDisplayMaxLen>::MAX_LEN;
+            }
+
+            impl<T> uDebugMaxLen for $Wrapper<T>
+            where
+                T: uDebugMaxLen,
+            {
+                const MAX_LEN: usize = <T as uDebugMaxLen>::MAX_LEN;
+            }
+        )*
+    }
+}
+
+transparent!(Saturating, Wrapping,);
@@ -18,8 +18,21 @@
 //!   example, the implementation for `std::String` uses [`Infallible`] as its error type.
 //! - [`core::fmt::Formatter::debug_struct`][debug_struct]-like API
 //! - [`#[derive(uDebug)]`][derive]
+//! - Compile-time upper bounds on the length of the formatted output, [`uDisplayMaxLen`] and
+//!   [`uDebugMaxLen`]
 //! - Pretty formatting (`{:#?}`) for `uDebug`
-//! - Hexadecimal formatting (`{:x}`) of integer primitives (e.g. `i32`) -- currently cannot be extended to other types
+//! - Hexadecimal formatting (`{:x}`) of integer primitives (e.g. `i32`) and byte slices --
+//!   currently cannot be extended to other types
+//! - `xxd`-style hex dumps of byte slices through the [`HexDump`] wrapper, and streaming
+//!   [`Base64`] and [`Base32`] encoders
+//! - [`core::panic!`]-like macros ([`upanic!`], [`uassert!`], [`uassert_eq!`], [`uunreachable!`])
+//!   that report through a user-supplied [`uPanic`] sink
+//! - Panic-free parsing of integers, with radix prefixes, through [`uFromStr`]
+//! - [`uscan!`], the input counterpart of `uwrite!`
+//! - Error reporting through [`uError`], with chains of sources printed by [`Report`], and
+//!   `#[derive(uError)]`
+//! - An optional, `defmt`-like [deferred formatting backend](deferred/index.html) (`deferred`
+//!   Cargo feature) that keeps format strings out of the device
 //!
 //! [`Debug`]: trait.uDebug.html
 //! [`Display`]: trait.uDisplay.html
@@ -29,6 +42,21 @@
 //! [`Infallible`]: https://doc.rust-lang.org/core/convert/enum.Infallible.html
 //! [debug_struct]: struct.Formatter.html#method.debug_struct
 //! [derive]: derive/index.html
+//! [`uDisplayMaxLen`]: trait.uDisplayMaxLen.html
+//! [`uDebugMaxLen`]: trait.uDebugMaxLen.html
+//! [`core::panic!`]: https://doc.rust-lang.org/core/macro.panic.html
+//! [`upanic!`]: macro.upanic.html
+//! [`uassert!`]: macro.uassert.html
+//! [`uassert_eq!`]: macro.uassert_eq.html
+//! [`uunreachable!`]: macro.uunreachable.html
+//! [`uPanic`]: panic/trait.uPanic.html
+//! [`uFromStr`]: trait.uFromStr.html
+//! [`uscan!`]: macro.uscan.html
+//! [`uError`]: error/trait.uError.html
+//! [`Report`]: error/struct.Report.html
+//! [`HexDump`]: struct.HexDump.html
+//! [`Base64`]: struct.Base64.html
+//! [`Base32`]: struct.Base32.html
 //!
 //! # Non-features
 //!
@@ -56,7 +84,8 @@
 //! - Hexadecimal formatting
 //!
 //! Lowercase (`{:x}`), uppercase (`{:X}`), `0x`-prefix (`{:#x}`) and padding (`{:02x}`) are
-//! supported on primitive integer types.
+//! supported on primitive integer types and on byte slices and arrays, which are written as a
+//! contiguous string of two-digit bytes.
 //!
 //! ```
 //! use ufmt::uwrite;
@@ -204,14 +233,15 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 // this lets us use `uwrite!` in the test suite
 #[allow(unused_extern_crates)]
 #[cfg(test)]
 extern crate self as ufmt;
 
-use core::str;
-
-pub use ufmt_write::uWrite;
+pub use ufmt_write::{uAsyncWrite, uWrite, uWriteBytes};
 
 /// Write formatted data into a buffer
 ///
@@ -238,13 +268,91 @@ pub use ufmt_macros::uwrite;
 /// See [`uwrite!`](macro.uwrite.html) for more details
 pub use ufmt_macros::uwriteln;
 
+/// Parse structured input according to a format string
+///
+/// This is the input counterpart of [`uwrite!`](macro.uwrite.html). The first argument is the
+/// `&str` to parse; the remaining arguments are places that receive the parsed fields.
+///
+/// - literal text must match the input exactly
+/// - `{}` - field parsed with `uFromStr`, or a `&str` borrowed from the input
+/// - `{:x}` - hexadecimal field, optionally prefixed with `0x`. Like `{:x}` in `uwrite!`, signed
+///   integers are read as two's complement
+///
+/// A field extends up to the next occurrence of the literal that follows it, or to the end of the
+/// input if it's the last piece; therefore two fields cannot be adjacent. On failure the returned
+/// [`ScanError`](struct.ScanError.html) reports which piece of the format string did not match.
+///
+/// ```
+/// use ufmt::{uscan, ScanError, ScanErrorKind};
+///
+/// let (mut name, mut value) = ("", 0u32);
+/// uscan!("set led 0x1f", "set {} {:x}", name, value).unwrap();
+/// assert_eq!((name, value), ("led", 0x1f));
+///
+/// assert_eq!(
+///     uscan!("set led ?", "set {} {:x}", name, value),
+///     Err(ScanError { piece: 3, kind: ScanErrorKind::Field }),
+/// );
+/// ```
+pub use ufmt_macros::uscan;
+
+/// Write a frame of the [deferred formatting backend](deferred/index.html) into a byte sink
+///
+/// The sink must have type `[&mut] impl uWriteBytes` and the arguments must implement
+/// `deferred::uEncode`. The format string syntax is the same as the one of
+/// [`uwrite!`](macro.uwrite.html) but the formatting happens on the host.
+#[cfg(all(
+    feature = "deferred",
+    not(any(
+        target_family = "wasm",
+        target_os = "uefi",
+        target_os = "windows",
+        target_vendor = "apple"
+    ))
+))]
+pub use ufmt_macros::uwrite_deferred;
+
+pub use crate::asynch::{
+    AsyncWriteError, UnstableBuffer, UnstableDoAsync, UnstableFull, UNSTABLE_DEFAULT_CAPACITY,
+};
 pub use crate::helpers::{DebugList, DebugMap, DebugStruct, DebugTuple};
+pub use crate::parse::{uFromStr, uFromStrHex, ParseIntError};
+pub use crate::scan::{
+    ScanError, ScanErrorKind, UnstableScanField, UnstableScanHex, UnstableScanner,
+};
 
+mod asynch;
+#[cfg(all(
+    feature = "deferred",
+    not(any(
+        target_family = "wasm",
+        target_os = "uefi",
+        target_os = "windows",
+        target_vendor = "apple"
+    ))
+))]
+pub mod deferred;
+
+// the deferred backend relies on ELF linker features; see the docs of the `deferred` module
+#[cfg(all(
+    feature = "deferred",
+    any(
+        target_family = "wasm",
+        target_os = "uefi",
+        target_os = "windows",
+        target_vendor = "apple"
+    )
+))]
+compile_error!("the `deferred` feature is only supported on targets that produce ELF object files");
+pub mod error;
 mod helpers;
 mod impls;
+pub mod panic;
+mod parse;
+mod scan;
 /// Derive macros
 pub mod derive {
-    pub use ufmt_macros::uDebug;
+    pub use ufmt_macros::{uDebug, uDebugMaxLen, uError};
 }
 
 /// Just like `core::fmt::Debug`
@@ -265,6 +373,57 @@ pub trait uDisplay {
         W: uWrite + ?Sized;
 }
 
+/// Upper bound on the length of a value's `uDisplay` output
+///
+/// `MAX_LEN` is the maximum number of bytes that the `{}` output of any value of the type can
+/// span. It can be used to size stack buffers (e.g. a `heapless::String`) that are guaranteed to
+/// never overflow. See [`uDebugMaxLen`] for the `{:?}` output.
+///
+/// ```
+/// use ufmt::uDisplayMaxLen;
+///
+/// // "-32768"
+/// assert_eq!(<i16 as uDisplayMaxLen>::MAX_LEN, 6);
+/// ```
+#[allow(non_camel_case_types)]
+pub trait uDisplayMaxLen: uDisplay {
+    /// Maximum length, in bytes, of the `uDisplay` output
+    const MAX_LEN: usize;
+}
+
+/// Upper bound on the length of a value's `uDebug` output
+///
+/// `MAX_LEN` is the maximum number of bytes that the `{:?}` output of any value of the type can
+/// span. Pretty (`{:#?}`) output is *not* covered as its length depends on the indentation level.
+///
+/// This trait can be derived for `struct`-s and `enum`-s that derive `uDebug`.
+///
+/// ```
+/// use ufmt::{derive::{uDebug, uDebugMaxLen}, uDebugMaxLen};
+///
+/// #[derive(uDebug, uDebugMaxLen)]
+/// struct Pair { x: i8, y: bool }
+///
+/// // "Pair { x: -128, y: false }"
+/// assert_eq!(Pair::MAX_LEN, 26);
+/// assert_eq!(<[u16; 4]>::MAX_LEN, 28);
+/// ```
+#[allow(non_camel_case_types)]
+pub trait uDebugMaxLen: uDebug {
+    /// Maximum length, in bytes, of the `uDebug` output
+    const MAX_LEN: usize;
+}
+
+// Implementation detail of `#[derive(uDebugMaxLen)]`
+#[doc(hidden)]
+pub const fn unstable_max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
 /// HEADS UP this is currently an implementation detail and not subject to semver guarantees.
 /// do NOT use this outside the `ufmt` crate
 // options for formatting hexadecimal numbers
@@ -286,18 +445,25 @@ impl HexOptions {
         &self,
         fmt: &mut Formatter<'_, W>,
         payload: &str,
+    ) -> Result<(), <W as uWrite>::Error> {
+        self.with_payload(fmt, payload.len(), |fmt| fmt.write_str(payload))
+    }
+
+    /// like `with_stuff` but the payload, which spans `len` bytes, is written by a closure
+    pub fn with_payload<W: uWrite + ?Sized>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        len: usize,
+        payload: impl FnOnce(&mut Formatter<'_, W>) -> Result<(), <W as uWrite>::Error>,
     ) -> Result<(), <W as uWrite>::Error> {
         let pad_before = self.ox_prefix && self.pad_char == b' ';
 
-        let pad = self.pad_length as isize
-            - (if self.ox_prefix { 2 } else { 0 } + payload.len()) as isize;
+        let pad = self.pad_length as isize - (if self.ox_prefix { 2 } else { 0 } + len) as isize;
 
         let do_pad = |fmt: &mut Formatter<'_, W>, pad: isize| -> Result<(), <W as uWrite>::Error> {
             if pad > 0 {
                 for _ in 0..pad {
-                    // miri considers the `write_char` defined in `ufmt-write` v0.1.0 unsound
-                    // to workaround the issue we use `write_str` instead of `write_char`
-                    fmt.write_str(unsafe { str::from_utf8_unchecked(&[self.pad_char]) })?;
+                    fmt.write_char(char::from(self.pad_char))?;
                 }
             }
             Ok(())
@@ -321,7 +487,223 @@ impl HexOptions {
             do_pad(fmt, pad)?;
         }
 
-        fmt.write_str(payload)
+        payload(fmt)
+    }
+}
+
+/// A wrapper that formats a byte slice as an `xxd`-style hex dump
+///
+/// Each line holds the offset of its first byte, the bytes in hexadecimal and an ASCII gutter where
+/// non-printable bytes are shown as `.`. By default lines hold 16 bytes in groups of 2, like
+/// `xxd`'s output; this can be changed with [`HexDump::width`] and [`HexDump::group`]. Lines are
+/// separated by newlines; the last line is not terminated.
+///
+/// ```
+/// use ufmt::{uwrite, HexDump};
+///
+/// let mut s = String::new();
+/// uwrite!(s, "{}", HexDump::new(b"Hello, world!\n\x00\x01\xff abc")).unwrap();
+/// assert_eq!(
+///     s,
+///     "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a 0001  Hello, world!...\n\
+///      00000010: ff20 6162 63                             . abc"
+/// );
+///
+/// let mut s = String::new();
+/// uwrite!(s, "{}", HexDump::new(b"Hello").width(4).group(1)).unwrap();
+/// assert_eq!(s, "00000000: 48 65 6c 6c  Hell\n00000004: 6f           o");
+/// ```
+///
+/// To format a byte slice as a contiguous hex string use `{:x}` instead: `uwrite!(s, "{:x}",
+/// bytes)` writes e.g. `48656c6c6f`; the usual `{:X}`, `{:#x}` and `{:08x}` variants work too.
+#[derive(Clone, Copy)]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    group: usize,
+}
+
+impl<'a> HexDump<'a> {
+    /// Creates a hex dump of `bytes` with 16 bytes per line in groups of 2
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            width: 16,
+            group: 2,
+        }
+    }
+
+    /// Sets the number of bytes per line; a `width` of 0 is treated as 1 and widths above 256,
+    /// `xxd`'s own limit, are treated as 256
+    pub const fn width(self, width: usize) -> Self {
+        Self {
+            width: if width == 0 {
+                1
+            } else if width > 256 {
+                256
+            } else {
+                width
+            },
+            ..self
+        }
+    }
+
+    /// Sets the number of bytes per space-separated group; a `group` of 0 disables grouping
+    pub const fn group(self, group: usize) -> Self {
+        Self { group, ..self }
+    }
+}
+
+/// A wrapper that formats a `Duration` in a fixed unit
+///
+/// Unlike the `uDebug` implementation of `Duration`, which picks the largest unit in which the
+/// integer part is not zero, the output always uses the given unit. The fractional part is written
+/// without trailing zeros.
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use ufmt::{uwrite, FixedUnit, TimeUnit};
+///
+/// let mut s = String::new();
+/// let timeout = Duration::from_micros(1_500_250);
+/// uwrite!(s, "{} {:?}", FixedUnit::new(timeout, TimeUnit::Millis), timeout).unwrap();
+/// assert_eq!(s, "1500.25ms 1.50025s");
+/// ```
+#[derive(Clone, Copy)]
+pub struct FixedUnit {
+    duration: core::time::Duration,
+    unit: TimeUnit,
+}
+
+impl FixedUnit {
+    /// Creates a wrapper that formats `duration` in the given `unit`
+    pub const fn new(duration: core::time::Duration, unit: TimeUnit) -> Self {
+        Self { duration, unit }
+    }
+}
+
+/// The unit that [`FixedUnit`] uses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// Seconds (`s`)
+    Seconds,
+    /// Milliseconds (`ms`)
+    Millis,
+    /// Microseconds (`µs`)
+    Micros,
+    /// Nanoseconds (`ns`)
+    Nanos,
+}
+
+/// A wrapper that formats a byte slice as base32 text (RFC 4648)
+///
+/// The text is encoded on the fly, without an intermediate buffer. By default the standard
+/// alphabet is used, the output is padded with `=` and it's written as a single line.
+///
+/// ```
+/// use ufmt::{uwrite, Base32};
+///
+/// let mut s = String::new();
+/// uwrite!(s, "{}", Base32::new(b"foobar")).unwrap();
+/// assert_eq!(s, "MZXW6YTBOI======");
+///
+/// let mut s = String::new();
+/// uwrite!(s, "{}", Base32::new(b"foobar").hex().padding(false).wrap(4)).unwrap();
+/// assert_eq!(s, "CPNM\nUOJ1\nE8");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Base32<'a> {
+    bytes: &'a [u8],
+    hex: bool,
+    padding: bool,
+    wrap: usize,
+}
+
+impl<'a> Base32<'a> {
+    /// Creates a padded, single-line encoding of `bytes` that uses the standard alphabet
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            hex: false,
+            padding: true,
+            wrap: 0,
+        }
+    }
+
+    /// Uses the "extended hex" alphabet (`0-9A-V`), which preserves the sort order of the data
+    pub const fn hex(self) -> Self {
+        Self { hex: true, ..self }
+    }
+
+    /// Sets whether the output is padded with `=` to a multiple of 8 characters
+    pub const fn padding(self, padding: bool) -> Self {
+        Self { padding, ..self }
+    }
+
+    /// Inserts a newline after every `width` characters; a `width` of 0 disables wrapping
+    pub const fn wrap(self, width: usize) -> Self {
+        Self {
+            wrap: width,
+            ..self
+        }
+    }
+}
+
+/// A wrapper that formats a byte slice as base64 text (RFC 4648)
+///
+/// The text is encoded on the fly, without an intermediate buffer. By default the standard
+/// alphabet is used, the output is padded with `=` and it's written as a single line.
+///
+/// ```
+/// use ufmt::{uwrite, Base64};
+///
+/// let mut s = String::new();
+/// uwrite!(s, "{}", Base64::new(b"foob")).unwrap();
+/// assert_eq!(s, "Zm9vYg==");
+///
+/// let mut s = String::new();
+/// uwrite!(s, "{}", Base64::new(&[0xfb, 0xff, 0xbf]).url_safe().wrap(2)).unwrap();
+/// assert_eq!(s, "-_\n-_");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Base64<'a> {
+    bytes: &'a [u8],
+    url_safe: bool,
+    padding: bool,
+    wrap: usize,
+}
+
+impl<'a> Base64<'a> {
+    /// Creates a padded, single-line encoding of `bytes` that uses the standard alphabet
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            url_safe: false,
+            padding: true,
+            wrap: 0,
+        }
+    }
+
+    /// Uses the URL and filename safe alphabet, where `-` and `_` replace `+` and `/`
+    pub const fn url_safe(self) -> Self {
+        Self {
+            url_safe: true,
+            ..self
+        }
+    }
+
+    /// Sets whether the output is padded with `=` to a multiple of 4 characters
+    pub const fn padding(self, padding: bool) -> Self {
+        Self { padding, ..self }
+    }
+
+    /// Inserts a newline after every `width` characters; a `width` of 0 disables wrapping
+    pub const fn wrap(self, width: usize) -> Self {
+        Self {
+            wrap: width,
+            ..self
+        }
     }
 }
 
@@ -393,6 +775,19 @@ where
     }
 }
 
+impl<W> Formatter<'_, W>
+where
+    W: uWriteBytes + ?Sized,
+{
+    /// Writes raw bytes to the underlying buffer contained within this formatter.
+    ///
+    /// Only available when the underlying writer implements [`uWriteBytes`]; text-only writers
+    /// can't hold arbitrary bytes.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), W::Error> {
+        self.writer.write_bytes(bytes)
+    }
+}
+
 // Implementation detail of the `uwrite*!` macros
 #[doc(hidden)]
 pub trait UnstableDoAsFormatter {
@@ -0,0 +1,175 @@
+//! `core::panic!`-like macros that format their message with `uwrite!`
+//!
+//! Unlike `core::panic!` these macros take an explicit sink, a [`uPanic`] writer, as their first
+//! argument. The message is written into the sink and then [`uPanic::panic`] is called; this hook
+//! must not return. As the message is never handed to the `#[panic_handler]` no `core::fmt` code
+//! is pulled into the binary.
+//!
+//! ```
+//! use core::convert::Infallible;
+//!
+//! use ufmt::{panic::uPanic, uWrite};
+//!
+//! struct Uart;
+//!
+//! impl uWrite for Uart {
+//!     type Error = Infallible;
+//!
+//!     fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+//!         // ..
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl uPanic for Uart {
+//!     fn panic(&mut self) -> ! {
+//!         // e.g. reset the device or trigger a breakpoint
+//!         # panic!();
+//!         loop {}
+//!     }
+//! }
+//!
+//! # #[allow(unreachable_code)]
+//! fn check(x: i32) {
+//!     ufmt::uassert!(Uart, x >= 0, "negative value: {}", x);
+//!     ufmt::uassert_eq!(Uart, x % 2, 0);
+//! }
+//! # check(2);
+//! ```
+//!
+//! Panics raised by `core::panic!` and friends still reach the `#[panic_handler]`. `PanicInfo`
+//! implements `uDisplay` so the handler can report them without `core::fmt`; the message is only
+//! included when it's a string literal.
+//!
+//! ``` ignore
+//! #[panic_handler]
+//! fn panic(info: &core::panic::PanicInfo<'_>) -> ! {
+//!     // e.g. "panicked at src/main.rs:12:5:\nexplicit panic"
+//!     ufmt::uwrite!(Uart, "{}", info).ok();
+//!     loop {}
+//! }
+//! ```
+
+use crate::{uWrite, Formatter};
+
+/// A writer that can be used as the sink of the `upanic!` family of macros
+#[allow(non_camel_case_types)]
+pub trait uPanic: uWrite {
+    /// Called after the panic message has been written into this writer
+    fn panic(&mut self) -> !;
+}
+
+// Implementation detail of the `upanic!` family of macros
+#[doc(hidden)]
+pub trait UnstableDoPanic {
+    type Writer: uPanic + ?Sized;
+
+    fn do_panic(
+        &mut self,
+        f: impl FnOnce(&mut Formatter<'_, Self::Writer>) -> Result<(), <Self::Writer as uWrite>::Error>,
+    ) -> !;
+}
+
+impl<P> UnstableDoPanic for P
+where
+    P: uPanic + ?Sized,
+{
+    type Writer = P;
+
+    fn do_panic(&mut self, f: impl FnOnce(&mut Formatter<'_, P>) -> Result<(), P::Error>) -> ! {
+        // the message is best effort; the hook must run regardless
+        let _ = f(&mut Formatter::new(self));
+        self.panic()
+    }
+}
+
+/// Writes a message into the given [`uPanic`] sink and then calls its diverging hook
+///
+/// `upanic!(sink)` writes `explicit panic`. `upanic!(sink, "..", args)` accepts the same format
+/// string syntax as [`uwrite!`](macro.uwrite.html).
+///
+/// [`uPanic`]: panic/trait.uPanic.html
+#[macro_export]
+macro_rules! upanic {
+    ($sink:expr $(,)?) => {
+        $crate::upanic!($sink, "explicit panic")
+    };
+    ($sink:expr, $($arg:tt)+) => {{
+        use $crate::panic::UnstableDoPanic as _;
+
+        ($sink).do_panic(|f| $crate::uwrite!(f, $($arg)+))
+    }};
+}
+
+/// Like [`core::assert!`] but the failure is reported through [`upanic!`](macro.upanic.html)
+///
+/// [`core::assert!`]: https://doc.rust-lang.org/core/macro.assert.html
+#[macro_export]
+macro_rules! uassert {
+    ($sink:expr, $cond:expr $(,)?) => {
+        if !$cond {
+            $crate::upanic!($sink, "assertion failed: {}", core::stringify!($cond))
+        }
+    };
+    ($sink:expr, $cond:expr, $($arg:tt)+) => {
+        if !$cond {
+            $crate::upanic!($sink, $($arg)+)
+        }
+    };
+}
+
+/// Like [`core::assert_eq!`] but the failure is reported through [`upanic!`](macro.upanic.html)
+///
+/// Both operands must implement `uDebug`.
+///
+/// [`core::assert_eq!`]: https://doc.rust-lang.org/core/macro.assert_eq.html
+#[macro_export]
+macro_rules! uassert_eq {
+    ($sink:expr, $left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::upanic!(
+                        $sink,
+                        "assertion `left == right` failed\n  left: {:?}\n right: {:?}",
+                        left,
+                        right
+                    )
+                }
+            }
+        }
+    };
+    ($sink:expr, $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    use $crate::panic::UnstableDoPanic as _;
+
+                    ($sink).do_panic(|f| {
+                        $crate::uwrite!(f, "assertion `left == right` failed: ")?;
+                        $crate::uwrite!(f, $($arg)+)?;
+                        $crate::uwrite!(f, "\n  left: {:?}\n right: {:?}", left, right)
+                    })
+                }
+            }
+        }
+    };
+}
+
+/// Like [`core::unreachable!`] but the failure is reported through [`upanic!`](macro.upanic.html)
+///
+/// [`core::unreachable!`]: https://doc.rust-lang.org/core/macro.unreachable.html
+#[macro_export]
+macro_rules! uunreachable {
+    ($sink:expr $(,)?) => {
+        $crate::upanic!($sink, "internal error: entered unreachable code")
+    };
+    ($sink:expr, $($arg:tt)+) => {{
+        use $crate::panic::UnstableDoPanic as _;
+
+        ($sink).do_panic(|f| {
+            $crate::uwrite!(f, "internal error: entered unreachable code: ")?;
+            $crate::uwrite!(f, $($arg)+)
+        })
+    }};
+}
@@ -0,0 +1,244 @@
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::{uDebug, uDisplay, uWrite, Formatter};
+
+/// Just like `core::str::FromStr`
+///
+/// The implementations for integers accept an optional sign (`+` or `-`) followed by an optional
+/// radix prefix (`0x`, `0o` or `0b`, in either case) and the digits.
+///
+/// ```
+/// use ufmt::{uFromStr, ParseIntError};
+///
+/// assert_eq!(i32::from_str("-0x1F"), Ok(-31));
+/// assert_eq!(u8::from_str("0b1010"), Ok(10));
+/// assert_eq!(u8::from_str("256"), Err(ParseIntError::PosOverflow));
+/// ```
+#[allow(non_camel_case_types)]
+pub trait uFromStr: Sized {
+    /// The error returned when parsing fails
+    type Err;
+
+    /// Parses a string `s` to return a value of this type
+    fn from_str(s: &str) -> Result<Self, Self::Err>;
+}
+
+/// An error which can be returned when parsing an integer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseIntError {
+    /// The string is empty
+    Empty,
+    /// The string contains an invalid digit, or no digits after the sign or the radix prefix
+    InvalidDigit,
+    /// The number is too large to fit in the target type
+    PosOverflow,
+    /// The number is too small to fit in the target type
+    NegOverflow,
+    /// The number is zero but the target type is a `NonZero*` type
+    Zero,
+}
+
+impl uDebug for ParseIntError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(match self {
+            ParseIntError::Empty => "Empty",
+            ParseIntError::InvalidDigit => "InvalidDigit",
+            ParseIntError::PosOverflow => "PosOverflow",
+            ParseIntError::NegOverflow => "NegOverflow",
+            ParseIntError::Zero => "Zero",
+        })
+    }
+}
+
+impl uDisplay for ParseIntError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        // same messages as `core::num::ParseIntError`
+        f.write_str(match self {
+            ParseIntError::Empty => "cannot parse integer from empty string",
+            ParseIntError::InvalidDigit => "invalid digit found in string",
+            ParseIntError::PosOverflow => "number too large to fit in target type",
+            ParseIntError::NegOverflow => "number too small to fit in target type",
+            ParseIntError::Zero => "number would be zero for non-zero type",
+        })
+    }
+}
+
+// splits `s` into its sign, radix and digits
+fn split(s: &[u8]) -> Result<(bool, u8, &[u8]), ParseIntError> {
+    let (negative, s) = match s {
+        [] => return Err(ParseIntError::Empty),
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        _ => (false, s),
+    };
+
+    let (radix, digits) = match s {
+        [b'0', b'x' | b'X', rest @ ..] => (16, rest),
+        [b'0', b'o' | b'O', rest @ ..] => (8, rest),
+        [b'0', b'b' | b'B', rest @ ..] => (2, rest),
+        _ => (10, s),
+    };
+
+    if digits.is_empty() {
+        Err(ParseIntError::InvalidDigit)
+    } else {
+        Ok((negative, radix, digits))
+    }
+}
+
+fn digit(byte: u8, radix: u8) -> Result<u8, ParseIntError> {
+    let d = match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'z' => byte - b'a' + 10,
+        b'A'..=b'Z' => byte - b'A' + 10,
+        _ => return Err(ParseIntError::InvalidDigit),
+    };
+
+    if d < radix {
+        Ok(d)
+    } else {
+        Err(ParseIntError::InvalidDigit)
+    }
+}
+
+/// HEADS UP this is currently an implementation detail and not subject to semver guarantees.
+/// do NOT use this outside the `ufmt` crate
+// the input counterpart of `uDisplayHex`; used by `uscan!`'s `{:x}` fields
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub trait uFromStrHex: Sized {
+    /// Parses hexadecimal digits, optionally preceded by a `0x` prefix
+    fn from_str_hex(s: &str) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! uxx {
+    ($($uxx:ty),+) => {
+        $(
+            impl uFromStr for $uxx {
+                type Err = ParseIntError;
+
+                fn from_str(s: &str) -> Result<Self, ParseIntError> {
+                    let (negative, radix, digits) = split(s.as_bytes())?;
+                    if negative {
+                        return Err(ParseIntError::InvalidDigit);
+                    }
+
+                    let mut n: $uxx = 0;
+                    for byte in digits {
+                        let d = digit(*byte, radix)?;
+                        n = n
+                            .checked_mul(radix as $uxx)
+                            .and_then(|n| n.checked_add(d as $uxx))
+                            .ok_or(ParseIntError::PosOverflow)?;
+                    }
+
+                    Ok(n)
+                }
+            }
+
+            impl uFromStrHex for $uxx {
+                fn from_str_hex(s: &str) -> Result<Self, ParseIntError> {
+                    let digits = match s.as_bytes() {
+                        [] => return Err(ParseIntError::Empty),
+                        [b'0', b'x' | b'X', rest @ ..] => rest,
+                        digits => digits,
+                    };
+
+                    if digits.is_empty() {
+                        return Err(ParseIntError::InvalidDigit);
+                    }
+
+                    let mut n: $uxx = 0;
+                    for byte in digits {
+                        let d = digit(*byte, 16)?;
+                        n = n
+                            .checked_mul(16)
+                            .and_then(|n| n.checked_add(d as $uxx))
+                            .ok_or(ParseIntError::PosOverflow)?;
+                    }
+
+                    Ok(n)
+                }
+            }
+        )+
+    }
+}
+
+uxx!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! ixx {
+    ($($ixx:ty : $uxx:ty),+) => {
+        $(
+            impl uFromStr for $ixx {
+                type Err = ParseIntError;
+
+                fn from_str(s: &str) -> Result<Self, ParseIntError> {
+                    let (negative, radix, digits) = split(s.as_bytes())?;
+
+                    let mut n: $ixx = 0;
+                    for byte in digits {
+                        let d = digit(*byte, radix)?;
+                        let m = n.checked_mul(radix as $ixx);
+                        n = if negative {
+                            m.and_then(|n| n.checked_sub(d as $ixx))
+                                .ok_or(ParseIntError::NegOverflow)?
+                        } else {
+                            m.and_then(|n| n.checked_add(d as $ixx))
+                                .ok_or(ParseIntError::PosOverflow)?
+                        };
+                    }
+
+                    Ok(n)
+                }
+            }
+
+            impl uFromStrHex for $ixx {
+                // like `{:x}` the digits are the two's complement representation of the number
+                #[inline(always)]
+                fn from_str_hex(s: &str) -> Result<Self, ParseIntError> {
+                    <$uxx as uFromStrHex>::from_str_hex(s).map(|n| n as $ixx)
+                }
+            }
+        )+
+    }
+}
+
+ixx!(i8: u8, i16: u16, i32: u32, i64: u64, i128: u128, isize: usize);
+
+macro_rules! nz {
+    ($($NZ:ident : $inner:ident,)*) => {
+        $(
+            impl uFromStr for $NZ {
+                type Err = ParseIntError;
+
+                fn from_str(s: &str) -> Result<Self, ParseIntError> {
+                    $NZ::new(<$inner as uFromStr>::from_str(s)?).ok_or(ParseIntError::Zero)
+                }
+            }
+        )*
+    }
+}
+
+nz!(
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroI8: i8,
+    NonZeroI128: i128,
+    NonZeroIsize: isize,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64,
+    NonZeroU8: u8,
+    NonZeroU128: u128,
+    NonZeroUsize: usize,
+);
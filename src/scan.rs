@@ -0,0 +1,184 @@
+use crate::{uDebug, uDisplay, uFromStr, uFromStrHex, uWrite, Formatter};
+
+/// An error returned by [`uscan!`](macro.uscan.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScanError {
+    /// Index of the format string piece that failed to match
+    ///
+    /// Pieces are the literal parts and the `{}` / `{:x}` fields of the format string, in order.
+    /// For example, in `"set {} {:x}"` piece 0 is `"set "` and piece 3 is `{:x}`.
+    pub piece: usize,
+    /// What went wrong
+    pub kind: ScanErrorKind,
+}
+
+/// The kind of [`ScanError`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanErrorKind {
+    /// The input does not contain the literal piece at the expected position
+    Literal,
+    /// The field could not be parsed
+    Field,
+    /// The input continues after the last piece of the format string; `piece` is the index of the
+    /// last piece
+    TrailingInput,
+}
+
+impl uDebug for ScanError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_struct("ScanError")?
+            .field("piece", &self.piece)?
+            .field("kind", &self.kind)?
+            .finish()
+    }
+}
+
+impl uDisplay for ScanError {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(match self.kind {
+            ScanErrorKind::Literal => "input does not match piece ",
+            ScanErrorKind::Field => "could not parse field ",
+            ScanErrorKind::TrailingInput => "trailing input after piece ",
+        })?;
+        <usize as uDisplay>::fmt(&self.piece, f)
+    }
+}
+
+impl uDebug for ScanErrorKind {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(match self {
+            ScanErrorKind::Literal => "Literal",
+            ScanErrorKind::Field => "Field",
+            ScanErrorKind::TrailingInput => "TrailingInput",
+        })
+    }
+}
+
+// Implementation detail of the `uscan!` macro
+#[doc(hidden)]
+pub struct UnstableScanner<'i> {
+    input: &'i str,
+}
+
+impl<'i> UnstableScanner<'i> {
+    pub fn new(input: &'i str) -> Self {
+        Self { input }
+    }
+
+    pub fn literal(&mut self, piece: usize, literal: &str) -> Result<(), ScanError> {
+        match self.input.strip_prefix(literal) {
+            Some(rest) => {
+                self.input = rest;
+                Ok(())
+            }
+            None => Err(ScanError {
+                piece,
+                kind: ScanErrorKind::Literal,
+            }),
+        }
+    }
+
+    // returns the input up to the next occurrence of `delimiter`, which is the literal piece that
+    // follows the field
+    pub fn field(&mut self, piece: usize, delimiter: &str) -> Result<&'i str, ScanError> {
+        let end = find(self.input, delimiter);
+        match end.and_then(|end| Some((self.input.get(..end)?, self.input.get(end..)?))) {
+            Some((field, rest)) => {
+                self.input = rest;
+                Ok(field)
+            }
+            None => Err(ScanError {
+                piece: piece + 1,
+                kind: ScanErrorKind::Literal,
+            }),
+        }
+    }
+
+    pub fn rest(&mut self) -> &'i str {
+        let rest = self.input;
+        self.input = "";
+        rest
+    }
+
+    pub fn finish(&self, piece: usize) -> Result<(), ScanError> {
+        if self.input.is_empty() {
+            Ok(())
+        } else {
+            Err(ScanError {
+                piece,
+                kind: ScanErrorKind::TrailingInput,
+            })
+        }
+    }
+}
+
+// returns the index of the first occurrence of `needle` in `haystack`
+//
+// NOTE `str::find` with a `&str` pattern uses the two-way searcher, which has panicking branches.
+// A match always starts at a `char` boundary because `needle` is valid UTF-8
+fn find(haystack: &str, needle: &str) -> Option<usize> {
+    let (haystack, needle) = (haystack.as_bytes(), needle.as_bytes());
+    (0..=haystack.len()).find(|&i| {
+        haystack
+            .get(i..)
+            .is_some_and(|rest| rest.starts_with(needle))
+    })
+}
+
+// Implementation detail of the `uscan!` macro
+#[doc(hidden)]
+pub trait UnstableScanField<'i> {
+    fn scan_field(&mut self, piece: usize, field: &'i str) -> Result<(), ScanError>;
+}
+
+impl<'i, T> UnstableScanField<'i> for T
+where
+    T: uFromStr,
+{
+    #[inline(always)]
+    fn scan_field(&mut self, piece: usize, field: &'i str) -> Result<(), ScanError> {
+        *self = T::from_str(field).map_err(|_| ScanError {
+            piece,
+            kind: ScanErrorKind::Field,
+        })?;
+        Ok(())
+    }
+}
+
+impl<'i> UnstableScanField<'i> for &'i str {
+    #[inline(always)]
+    fn scan_field(&mut self, _: usize, field: &'i str) -> Result<(), ScanError> {
+        *self = field;
+        Ok(())
+    }
+}
+
+// Implementation detail of the `uscan!` macro
+#[doc(hidden)]
+pub trait UnstableScanHex {
+    fn scan_hex(&mut self, piece: usize, field: &str) -> Result<(), ScanError>;
+}
+
+impl<T> UnstableScanHex for T
+where
+    T: uFromStrHex,
+{
+    #[inline(always)]
+    fn scan_hex(&mut self, piece: usize, field: &str) -> Result<(), ScanError> {
+        // padding produced by e.g. `{:4x}`
+        *self = T::from_str_hex(field.trim_start_matches(' ')).map_err(|_| ScanError {
+            piece,
+            kind: ScanErrorKind::Field,
+        })?;
+        Ok(())
+    }
+}
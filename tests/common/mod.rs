@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests
+
+macro_rules! uformat {
+    ($($tt:tt)*) => {{
+        let mut s = std::string::String::new();
+        #[allow(unreachable_code)]
+        match ufmt::uwrite!(&mut s, $($tt)*) {
+            Ok(_) => Ok(s),
+            Err(e) => Err(e),
+        }
+    }};
+}
+
+#[allow(unused_macros)]
+macro_rules! cmp {
+    ($($tt:tt)*) => {
+        assert_eq!(
+            uformat!($($tt)*),
+            Ok(format!($($tt)*)),
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use heapless::{spsc::Queue, Deque, FnvIndexMap, LinearMap, String, Vec};
+use ufmt::{uDebugMaxLen, uDisplayMaxLen, uwrite};
+
+#[macro_use]
+mod common;
+
+#[test]
+fn collections() {
+    let vec = Vec::<_, 4>::from_slice(&[1, 2]).unwrap();
+    cmp!("{:?} {:#?}", vec, Vec::<i32, 4>::new());
+
+    let string = String::<8>::from("héllo");
+    cmp!("{}", string);
+
+    let mut deque = Deque::<_, 4>::new();
+    deque.push_back(2).unwrap();
+    deque.push_front(1).unwrap();
+    cmp!("{:?} {:#?}", deque, deque);
+
+    let mut queue = Queue::<_, 4>::new();
+    queue.enqueue(1).unwrap();
+    queue.enqueue(2).unwrap();
+    queue.dequeue();
+    queue.enqueue(3).unwrap();
+    cmp!("{:?}", queue);
+
+    let mut index = FnvIndexMap::<_, _, 4>::new();
+    index.insert(2, 20).unwrap();
+    index.insert(1, 10).unwrap();
+    let mut linear = LinearMap::<_, _, 4>::new();
+    linear.insert(1, [2]).unwrap();
+    linear.insert(3, [4]).unwrap();
+    cmp!("{:?} {:#?}", index, linear);
+
+    assert_eq!(<String<8> as uDisplayMaxLen>::MAX_LEN, 8);
+    assert_eq!(<Vec<u8, 2> as uDebugMaxLen>::MAX_LEN, "[255, 255]".len());
+}
+
+#[test]
+fn writers() {
+    let mut s = String::<8>::new();
+    uwrite!(s, "{}-{}", 12, 'é').unwrap();
+    assert_eq!(s, "12-é");
+    // doesn't fit
+    assert_eq!(uwrite!(s, "{}", 12345), Err(()));
+    assert_eq!(s, "12-é");
+
+    let mut v = Vec::<u8, 4>::new();
+    uwrite!(v, "{}", 'é').unwrap();
+    assert_eq!(v, "é".as_bytes());
+    assert_eq!(uwrite!(v, "{}", 123), Err(()));
+}
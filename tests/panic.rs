@@ -0,0 +1,38 @@
+//! The `uDisplay` implementation of the `PanicHookInfo` (formerly `PanicInfo`) handed to a panic
+//! hook matches `std`'s
+
+use std::{
+    panic::{self, PanicHookInfo},
+    sync::Mutex,
+};
+
+#[macro_use]
+mod common;
+
+// (ufmt, std) renderings of the last panic
+static LAST: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+fn hook(info: &PanicHookInfo<'_>) {
+    *LAST.lock().unwrap() = Some((uformat!("{}", info).unwrap(), format!("{}", info)));
+}
+
+fn check(f: impl FnOnce() + panic::UnwindSafe) {
+    assert!(panic::catch_unwind(f).is_err());
+
+    let (ufmt, std) = LAST.lock().unwrap().take().unwrap();
+    assert_eq!(ufmt, std);
+}
+
+#[test]
+fn panic_info() {
+    panic::set_hook(Box::new(hook));
+
+    check(|| panic!("boom"));
+    check(|| panic!("x = {}", 42));
+    check(|| panic::panic_any(1u8));
+    check(|| {
+        let _ = [0; 2].get(3).unwrap();
+    });
+
+    drop(panic::take_hook());
+}
@@ -1,27 +1,11 @@
 use core::convert::Infallible;
+use core::num::ParseIntError;
 use std::collections::{BTreeMap, BTreeSet};
 
 use ufmt::{derive::uDebug, uDebug, uWrite, uwrite, uwriteln, Formatter};
 
-macro_rules! uformat {
-    ($($tt:tt)*) => {{
-        let mut s = String::new();
-        #[allow(unreachable_code)]
-        match ufmt::uwrite!(&mut s, $($tt)*) {
-            Ok(_) => Ok(s),
-            Err(e) => Err(e),
-        }
-    }};
-}
-
-macro_rules! cmp {
-    ($($tt:tt)*) => {
-        assert_eq!(
-            uformat!($($tt)*),
-            Ok(format!($($tt)*)),
-        )
-    }
-}
+#[macro_use]
+mod common;
 
 #[test]
 fn core() {
@@ -68,12 +52,12 @@ fn uxx() {
     cmp!("{}", 100u8);
 
     // extreme values
-    cmp!("{}", u8::max_value());
-    cmp!("{}", u16::max_value());
-    cmp!("{}", u32::max_value());
-    cmp!("{}", u64::max_value());
-    cmp!("{}", u128::max_value());
-    cmp!("{}", usize::max_value());
+    cmp!("{}", u8::MAX);
+    cmp!("{}", u16::MAX);
+    cmp!("{}", u32::MAX);
+    cmp!("{}", u64::MAX);
+    cmp!("{}", u128::MAX);
+    cmp!("{}", usize::MAX);
 }
 
 #[test]
@@ -84,18 +68,18 @@ fn ixx() {
     cmp!("{}", 100i8);
 
     // extreme values
-    cmp!("{}", i8::min_value());
-    cmp!("{}", i8::max_value());
-    cmp!("{}", i16::min_value());
-    cmp!("{}", i16::max_value());
-    cmp!("{}", i32::min_value());
-    cmp!("{}", i32::max_value());
-    cmp!("{}", i64::min_value());
-    cmp!("{}", i64::max_value());
-    cmp!("{}", i128::min_value());
-    cmp!("{}", i128::max_value());
-    cmp!("{}", isize::min_value());
-    cmp!("{}", isize::max_value());
+    cmp!("{}", i8::MIN);
+    cmp!("{}", i8::MAX);
+    cmp!("{}", i16::MIN);
+    cmp!("{}", i16::MAX);
+    cmp!("{}", i32::MIN);
+    cmp!("{}", i32::MAX);
+    cmp!("{}", i64::MIN);
+    cmp!("{}", i64::MAX);
+    cmp!("{}", i128::MIN);
+    cmp!("{}", i128::MAX);
+    cmp!("{}", isize::MIN);
+    cmp!("{}", isize::MAX);
 }
 
 #[test]
@@ -196,6 +180,7 @@ fn enum_() {
 }
 
 #[test]
+#[allow(clippy::manual_dangling_ptr)]
 fn ptr() {
     cmp!("{:?}", 1 as *const u8);
     cmp!("{:?}", 0xf as *const u8);
@@ -243,6 +228,22 @@ fn slice() {
     cmp!("{:#?}", [0, 1]);
 }
 
+#[test]
+fn array() {
+    #[derive(uDebug, Debug)]
+    struct Buffers {
+        bytes: [u8; 64],
+        words: [u16; 256],
+    }
+
+    let buffers = Buffers {
+        bytes: [0xff; 64],
+        words: [1; 256],
+    };
+    cmp!("{:?}", buffers);
+    cmp!("{:#?}", [[0u8; 33]; 2]);
+}
+
 #[test]
 fn uwriteln() {
     let mut s = String::new();
@@ -349,18 +350,878 @@ fn hex() {
     cmp!("{:#x}", 71);
 
     // extreme values
-    cmp!("{:x}", i8::min_value());
-    cmp!("{:x}", i8::max_value());
-    cmp!("{:x}", i16::min_value());
-    cmp!("{:x}", i16::max_value());
-    cmp!("{:x}", i32::min_value());
-    cmp!("{:x}", i32::max_value());
-    cmp!("{:x}", i64::min_value());
-    cmp!("{:x}", i64::max_value());
-    cmp!("{:x}", i128::min_value());
-    cmp!("{:x}", i128::max_value());
-    cmp!("{:x}", isize::min_value());
-    cmp!("{:x}", isize::max_value());
+    cmp!("{:x}", i8::MIN);
+    cmp!("{:x}", i8::MAX);
+    cmp!("{:x}", i16::MIN);
+    cmp!("{:x}", i16::MAX);
+    cmp!("{:x}", i32::MIN);
+    cmp!("{:x}", i32::MAX);
+    cmp!("{:x}", i64::MIN);
+    cmp!("{:x}", i64::MAX);
+    cmp!("{:x}", i128::MIN);
+    cmp!("{:x}", i128::MAX);
+    cmp!("{:x}", isize::MIN);
+    cmp!("{:x}", isize::MAX);
 
     // <i8 as std::fmt::Display>::fmt(-128)
 }
+
+#[test]
+fn hex_bytes() {
+    // std has no `{:x}` for byte slices; build the expected output from the bytes' hex
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    assert_eq!(uformat!("{:x}", bytes.as_slice()), Ok(hex(&bytes)));
+    assert_eq!(uformat!("{:x}", &bytes[..0]), Ok(String::new()));
+    assert_eq!(
+        uformat!("{:X}", [0xde, 0xad, 0xbe, 0xefu8]),
+        Ok("DEADBEEF".to_string())
+    );
+    assert_eq!(
+        uformat!("{:#x} {:#10X}", &[1u8, 2], &mut [0xabu8]),
+        Ok("0x0102       0XAB".to_string())
+    );
+    assert_eq!(uformat!("{:06x}", b"\n"), Ok("00000a".to_string()));
+}
+
+#[test]
+fn base32_base64() {
+    use ufmt::{Base32, Base64};
+
+    // test vectors from RFC 4648
+    let vectors: [(&[u8], &str, &str, &str); 7] = [
+        (b"", "", "", ""),
+        (b"f", "Zg==", "MY======", "CO======"),
+        (b"fo", "Zm8=", "MZXQ====", "CPNG===="),
+        (b"foo", "Zm9v", "MZXW6===", "CPNMU==="),
+        (b"foob", "Zm9vYg==", "MZXW6YQ=", "CPNMUOG="),
+        (b"fooba", "Zm9vYmE=", "MZXW6YTB", "CPNMUOJ1"),
+        (
+            b"foobar",
+            "Zm9vYmFy",
+            "MZXW6YTBOI======",
+            "CPNMUOJ1E8======",
+        ),
+    ];
+    for (bytes, base64, base32, base32_hex) in vectors {
+        assert_eq!(uformat!("{}", Base64::new(bytes)), Ok(base64.to_string()));
+        assert_eq!(uformat!("{}", Base32::new(bytes)), Ok(base32.to_string()));
+        assert_eq!(
+            uformat!("{}", Base32::new(bytes).hex()),
+            Ok(base32_hex.to_string())
+        );
+        assert_eq!(
+            uformat!("{}", Base64::new(bytes).padding(false)),
+            Ok(base64.trim_end_matches('=').to_string())
+        );
+        assert_eq!(
+            uformat!("{}", Base32::new(bytes).padding(false)),
+            Ok(base32.trim_end_matches('=').to_string())
+        );
+    }
+
+    assert_eq!(
+        uformat!("{}", Base64::new(&[0xfb, 0xff])),
+        Ok("+/8=".to_string())
+    );
+    assert_eq!(
+        uformat!("{}", Base64::new(&[0xfb, 0xff]).url_safe()),
+        Ok("-_8=".to_string())
+    );
+
+    // longer than the internal staging buffer
+    let bytes: Vec<u8> = (0..=255).collect();
+    let line = uformat!("{}", Base64::new(&bytes)).unwrap();
+    assert_eq!(line.len(), 344);
+    let wrapped = uformat!("{}", Base64::new(&bytes).wrap(76)).unwrap();
+    assert!(wrapped.lines().all(|l| l.len() <= 76));
+    assert!(!wrapped.ends_with('\n'));
+    assert_eq!(wrapped.replace('\n', ""), line);
+    assert!(line.starts_with("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4v"));
+    assert!(line.ends_with("8PHy8/T19vf4+fr7/P3+/w=="));
+}
+
+#[test]
+fn duration() {
+    use core::time::Duration;
+
+    use ufmt::{FixedUnit, TimeUnit};
+
+    for d in [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::from_nanos(999),
+        Duration::from_nanos(1_000),
+        Duration::from_nanos(1_500),
+        Duration::from_micros(12),
+        Duration::from_nanos(999_999),
+        Duration::from_millis(250),
+        Duration::from_nanos(1_000_001),
+        Duration::from_nanos(999_999_999),
+        Duration::from_secs(1),
+        Duration::from_millis(1_500),
+        Duration::new(3, 100),
+        Duration::MAX,
+    ] {
+        cmp!("{:?}", d);
+        cmp!("{:#?}", d);
+    }
+
+    let d = Duration::new(2, 5_000_600);
+    assert_eq!(
+        uformat!(
+            "{} {} {} {}",
+            FixedUnit::new(d, TimeUnit::Seconds),
+            FixedUnit::new(d, TimeUnit::Millis),
+            FixedUnit::new(d, TimeUnit::Micros),
+            FixedUnit::new(d, TimeUnit::Nanos)
+        ),
+        Ok("2.0050006s 2005.0006ms 2005000.6µs 2005000600ns".to_string())
+    );
+    assert_eq!(
+        uformat!("{}", FixedUnit::new(Duration::MAX, TimeUnit::Nanos)),
+        Ok(format!("{}ns", Duration::MAX.as_nanos()))
+    );
+    assert_eq!(
+        uformat!("{}", FixedUnit::new(Duration::ZERO, TimeUnit::Millis)),
+        Ok("0ms".to_string())
+    );
+}
+
+#[test]
+fn net() {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    let v4 = [
+        Ipv4Addr::UNSPECIFIED,
+        Ipv4Addr::LOCALHOST,
+        Ipv4Addr::BROADCAST,
+        Ipv4Addr::new(192, 168, 0, 10),
+    ];
+    let v6 = [
+        Ipv6Addr::UNSPECIFIED,
+        Ipv6Addr::LOCALHOST,
+        Ipv6Addr::new(1, 0, 0, 0, 0, 0, 0, 0),
+        Ipv6Addr::new(0xfe80, 0, 0, 0, 0x1ff, 0xfe23, 0x4567, 0x890a),
+        Ipv6Addr::new(1, 0, 0, 2, 0, 0, 0, 3),
+        // on ties the leftmost run is compressed
+        Ipv6Addr::new(1, 0, 0, 2, 0, 0, 3, 4),
+        // a single zero segment is not compressed
+        Ipv6Addr::new(1, 0, 2, 3, 4, 5, 6, 7),
+        Ipv6Addr::new(
+            0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
+        ),
+        // IPv4-mapped and IPv4-compatible
+        Ipv4Addr::new(1, 2, 3, 4).to_ipv6_mapped(),
+        Ipv4Addr::new(1, 2, 3, 4).to_ipv6_compatible(),
+        Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0, 0),
+    ];
+
+    for ip in v4 {
+        cmp!("{}", ip);
+        cmp!("{:?}", ip);
+        cmp!("{}", IpAddr::V4(ip));
+        cmp!("{:?}", IpAddr::V4(ip));
+
+        for port in [0, 80, 65535] {
+            let addr = SocketAddrV4::new(ip, port);
+            cmp!("{}", addr);
+            cmp!("{:?}", addr);
+            cmp!("{}", SocketAddr::V4(addr));
+            cmp!("{:#?}", SocketAddr::V4(addr));
+        }
+    }
+
+    for ip in v6 {
+        cmp!("{}", ip);
+        cmp!("{:?}", ip);
+        cmp!("{}", IpAddr::V6(ip));
+        cmp!("{:#?}", IpAddr::V6(ip));
+
+        for (port, flowinfo, scope_id) in [(0, 0, 0), (8080, 7, 0), (65535, 0, 3)] {
+            let addr = SocketAddrV6::new(ip, port, flowinfo, scope_id);
+            cmp!("{}", addr);
+            cmp!("{:?}", addr);
+            cmp!("{}", SocketAddr::V6(addr));
+            cmp!("{:?}", SocketAddr::V6(addr));
+        }
+    }
+}
+
+#[test]
+fn hexdump() {
+    use ufmt::HexDump;
+
+    // expected outputs produced by `xxd`
+    let bytes = b"Hello, world!\n\x00\x01\xff abcdefghijklmnop";
+    assert_eq!(
+        uformat!("{}", HexDump::new(bytes)),
+        Ok(
+            "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a 0001  Hello, world!...\n\
+            00000010: ff20 6162 6364 6566 6768 696a 6b6c 6d6e  . abcdefghijklmn\n\
+            00000020: 6f70                                     op"
+                .to_string()
+        )
+    );
+
+    // `xxd -g1 -c8`
+    assert_eq!(
+        uformat!("{}", HexDump::new(b"Hello, world!\n").width(8).group(1)),
+        Ok("00000000: 48 65 6c 6c 6f 2c 20 77  Hello, w\n\
+            00000008: 6f 72 6c 64 21 0a        orld!."
+            .to_string())
+    );
+
+    // `xxd -g0`
+    assert_eq!(
+        uformat!("{}", HexDump::new(b"Hello").group(0)),
+        Ok("00000000: 48656c6c6f                        Hello".to_string())
+    );
+
+    // `xxd -g4 -c6`
+    assert_eq!(
+        uformat!("{}", HexDump::new(b"Hello").width(6).group(4)),
+        Ok("00000000: 48656c6c 6f    Hello".to_string())
+    );
+
+    assert_eq!(uformat!("{}", HexDump::new(&[])), Ok(String::new()));
+    assert_eq!(
+        uformat!("{}", HexDump::new(&[0x7f; 2]).width(0)),
+        Ok("00000000: 7f  .\n00000001: 7f  .".to_string())
+    );
+    // the padding of the hex column is bounded
+    assert_eq!(
+        uformat!("{}", HexDump::new(b"x").width(usize::MAX)),
+        uformat!("{}", HexDump::new(b"x").width(256)),
+    );
+}
+
+#[test]
+fn upanic() {
+    use std::panic::{self, UnwindSafe};
+
+    use ufmt::{panic::uPanic, uassert, uassert_eq, upanic, uunreachable};
+
+    struct Sink(String);
+
+    impl uWrite for Sink {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    impl uPanic for Sink {
+        fn panic(&mut self) -> ! {
+            panic::panic_any(self.0.clone())
+        }
+    }
+
+    fn message(f: impl FnOnce() + UnwindSafe) -> String {
+        let payload = panic::catch_unwind(f).unwrap_err();
+        match payload.downcast::<String>() {
+            Ok(s) => *s,
+            Err(payload) => String::from(*payload.downcast::<&str>().unwrap()),
+        }
+    }
+
+    macro_rules! cmp_panic {
+        ($umac:ident, $mac:ident, $($tt:tt)*) => {
+            assert_eq!(
+                message(|| $umac!(Sink(String::new()), $($tt)*)),
+                message(|| $mac!($($tt)*)),
+            )
+        }
+    }
+
+    assert_eq!(
+        message(|| upanic!(Sink(String::new()))),
+        message(|| panic!())
+    );
+    assert_eq!(
+        message(|| upanic!(&mut Sink(String::new()), "x = {}", 1)),
+        message(|| panic!("x = {}", 1)),
+    );
+
+    let x = 1;
+    cmp_panic!(uassert, assert, x == 2);
+    cmp_panic!(uassert, assert, x == 2, "x = {:?}", x);
+    cmp_panic!(uassert_eq, assert_eq, x, 2);
+    cmp_panic!(uassert_eq, assert_eq, Some(x), None);
+    cmp_panic!(uassert_eq, assert_eq, x, 2, "{:#x}", 255);
+    assert_eq!(
+        message(|| uunreachable!(Sink(String::new()))),
+        message(|| unreachable!()),
+    );
+    assert_eq!(
+        message(|| uunreachable!(Sink(String::new()), "state {}", 3)),
+        message(|| unreachable!("state {}", 3)),
+    );
+
+    // no panic
+    let mut sink = Sink(String::new());
+    uassert!(sink, x == 1);
+    uassert_eq!(&mut sink, x, 1, "unused {}", 0);
+    assert!(sink.0.is_empty());
+}
+
+#[test]
+fn from_str() {
+    use core::num::{IntErrorKind, NonZeroI8, NonZeroU32};
+
+    use ufmt::{uFromStr, ParseIntError};
+
+    fn kind(e: &core::num::ParseIntError) -> ParseIntError {
+        match e.kind() {
+            IntErrorKind::Empty => ParseIntError::Empty,
+            IntErrorKind::InvalidDigit => ParseIntError::InvalidDigit,
+            IntErrorKind::PosOverflow => ParseIntError::PosOverflow,
+            IntErrorKind::NegOverflow => ParseIntError::NegOverflow,
+            IntErrorKind::Zero => ParseIntError::Zero,
+            _ => unreachable!(),
+        }
+    }
+
+    macro_rules! cmp_parse {
+        ($($ty:ty),+; $s:expr) => {
+            $(
+                assert_eq!(
+                    <$ty as uFromStr>::from_str($s),
+                    $s.parse::<$ty>().map_err(|e| kind(&e)),
+                    "{:?} as {}", $s, stringify!($ty),
+                );
+            )+
+        };
+    }
+
+    for s in [
+        "",
+        "0",
+        "+0",
+        "-0",
+        "1",
+        "-1",
+        "+",
+        "-",
+        "127",
+        "128",
+        "-128",
+        "-129",
+        "255",
+        "256",
+        "65535",
+        "-32769",
+        "4294967295",
+        "4294967296",
+        "1a",
+        " 1",
+        "١",
+    ] {
+        cmp_parse!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize; s);
+        cmp_parse!(NonZeroI8, NonZeroU32; s);
+    }
+
+    cmp_parse!(u128; "340282366920938463463374607431768211455");
+    cmp_parse!(u128; "340282366920938463463374607431768211456");
+    cmp_parse!(i128; "-170141183460469231731687303715884105728");
+    cmp_parse!(i128; "-170141183460469231731687303715884105729");
+
+    // radix prefixes
+    assert_eq!(u8::from_str("0xff"), Ok(0xff));
+    assert_eq!(u8::from_str("0XFf"), Ok(0xff));
+    assert_eq!(u8::from_str("0x100"), Err(ParseIntError::PosOverflow));
+    assert_eq!(i8::from_str("-0x80"), Ok(i8::MIN));
+    assert_eq!(i8::from_str("-0x81"), Err(ParseIntError::NegOverflow));
+    assert_eq!(u16::from_str("0o777"), Ok(0o777));
+    assert_eq!(u16::from_str("0o8"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(i32::from_str("+0b101"), Ok(5));
+    assert_eq!(i32::from_str("0b102"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u32::from_str("0x"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u32::from_str("-0x1"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(NonZeroU32::from_str("0x0"), Err(ParseIntError::Zero));
+
+    // error messages
+    for s in ["", "x", "256", "-129", "0"] {
+        let e = s.parse::<NonZeroI8>().unwrap_err();
+        assert_eq!(uformat!("{}", kind(&e)).unwrap(), e.to_string());
+    }
+}
+
+#[test]
+fn uscan() {
+    use ufmt::{uscan, ScanError, ScanErrorKind};
+
+    let (mut name, mut value, mut offset) = ("", 0u32, 0i8);
+    uscan!("set led {42}", "set {} {{{}}}", name, value).unwrap();
+    assert_eq!((name, value), ("led", 42));
+
+    // round trip
+    let s = uformat!("{} {:#x} {:4x}", "ufmt", 0xdeadu32, -2i8).unwrap();
+    uscan!(&s, "{} {:#x} {:4x}", name, value, offset).unwrap();
+    assert_eq!((name, value, offset), ("ufmt", 0xdead, -2));
+
+    // multi-byte fields and delimiters
+    uscan!("αβ→7", "{}→{}", name, value).unwrap();
+    assert_eq!((name, value), ("αβ", 7));
+
+    let err = |piece, kind| Err(ScanError { piece, kind });
+    assert_eq!(
+        uscan!("get led 1", "set {} {}", name, value),
+        err(0, ScanErrorKind::Literal)
+    );
+    assert_eq!(
+        uscan!("set led", "set {} {}", name, value),
+        err(2, ScanErrorKind::Literal)
+    );
+    assert_eq!(
+        uscan!("set led -1", "set {} {}", name, value),
+        err(3, ScanErrorKind::Field)
+    );
+    assert_eq!(uscan!("set led 1;", "set {} {};", name, value), Ok(()),);
+    assert_eq!(
+        uscan!("set led 1;;", "set {} {};", name, value),
+        err(4, ScanErrorKind::TrailingInput)
+    );
+    assert_eq!(
+        uformat!(
+            "{}",
+            ScanError {
+                piece: 3,
+                kind: ScanErrorKind::Field
+            }
+        )
+        .unwrap(),
+        "could not parse field 3"
+    );
+}
+
+#[test]
+fn max_len() {
+    use core::num::NonZeroI16;
+
+    use ufmt::{derive::uDebugMaxLen, uDebugMaxLen, uDisplayMaxLen};
+
+    macro_rules! tight {
+        ($($val:expr),+) => {
+            $(
+                assert_eq!(uformat!("{:?}", $val).unwrap().len(), max_len(&$val), "{:?}", $val);
+            )+
+        };
+    }
+
+    macro_rules! tight_display {
+        ($($val:expr),+) => {
+            $(
+                assert_eq!(uformat!("{}", $val).unwrap().len(), display_max_len(&$val), "{}", $val);
+            )+
+        };
+    }
+
+    fn max_len<T: uDebugMaxLen>(_: &T) -> usize {
+        T::MAX_LEN
+    }
+
+    fn display_max_len<T: uDisplayMaxLen>(_: &T) -> usize {
+        T::MAX_LEN
+    }
+
+    tight!(u8::MAX, u16::MAX, u32::MAX, u64::MAX, u128::MAX, usize::MAX);
+    tight!(i8::MIN, i16::MIN, i32::MIN, i64::MIN, i128::MIN, isize::MIN);
+    tight!(NonZeroI16::MIN, false, (), (i8::MIN,), (u8::MAX, false));
+    tight!(
+        [0u8; 0],
+        [u8::MAX; 3],
+        Some(i8::MIN),
+        Err::<u8, i8>(i8::MIN)
+    );
+    tight_display!(
+        u64::MAX,
+        i128::MIN,
+        isize::MIN,
+        NonZeroI16::MIN,
+        false,
+        '\u{10ffff}'
+    );
+
+    #[derive(uDebug, uDebugMaxLen, Debug)]
+    struct Unit;
+
+    #[derive(uDebug, uDebugMaxLen, Debug)]
+    struct Tuple(i8, bool);
+
+    #[derive(uDebug, uDebugMaxLen, Debug)]
+    struct Pair<T> {
+        x: T,
+        y: [i8; 2],
+    }
+
+    #[derive(uDebug, uDebugMaxLen, Debug)]
+    enum E {
+        A,
+        B(Tuple),
+        C { x: u32, y: u32 },
+    }
+
+    tight!(
+        Unit,
+        Tuple(i8::MIN, false),
+        Pair {
+            x: u64::MAX,
+            y: [i8::MIN; 2]
+        }
+    );
+    tight!(E::C {
+        x: u32::MAX,
+        y: u32::MAX
+    });
+    assert!(uformat!("{:?}", E::A).unwrap().len() < E::MAX_LEN);
+    assert!(uformat!("{:?}", E::B(Tuple(i8::MIN, false))).unwrap().len() < E::MAX_LEN);
+}
+
+#[test]
+fn write_bytes() {
+    use ufmt::uWriteBytes;
+
+    // a length-prefixed binary frame
+    struct Frame<'a>(&'a [u8]);
+
+    impl Frame<'_> {
+        fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: uWriteBytes + ?Sized,
+        {
+            f.write_bytes(&[self.0.len() as u8])?;
+            f.write_bytes(self.0)
+        }
+    }
+
+    let mut v = Vec::new();
+    let mut f = Formatter::new(&mut v);
+    uwrite!(f, "frame: ").unwrap();
+    Frame(&[0xff, 0x00]).fmt(&mut f).unwrap();
+    f.write_str("!").unwrap();
+    assert_eq!(v, b"frame: \x02\xff\x00!");
+}
+
+#[test]
+fn uwrite_async() {
+    use core::{
+        cell::Cell,
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use ufmt::{uAsyncWrite, uwrite_async, AsyncWriteError};
+
+    fn block_on<F: Future>(f: F) -> F::Output {
+        let mut f = pin!(f);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = f.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Chunks(Vec<String>);
+
+    impl uAsyncWrite for Chunks {
+        type Error = Infallible;
+
+        async fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push(s.to_string());
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, uDebug)]
+    struct Pair {
+        x: i32,
+        y: i32,
+    }
+
+    let pair = Pair { x: -1, y: i32::MIN };
+    let mut w = Chunks::default();
+    block_on(uwrite_async!(w, "{:#?} αβγ 🦀 {:#x}", pair, u64::MAX)).unwrap();
+    assert_eq!(w.0, [format!("{:#?} αβγ 🦀 {:#x}", pair, u64::MAX)]);
+
+    let mut w = Chunks::default();
+    block_on(uwrite_async!(&mut w, "")).unwrap();
+    assert!(w.0.is_empty());
+
+    // the arguments are formatted once, up front
+    struct Counter(Cell<u8>);
+
+    impl uDebug for Counter {
+        fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+        where
+            W: ufmt::uWrite + ?Sized,
+        {
+            self.0.set(self.0.get() + 1);
+            f.write_str(&"x".repeat(100))
+        }
+    }
+
+    let counter = Counter(Cell::new(0));
+    let mut w = Chunks::default();
+    block_on(uwrite_async!(w, "{:?}{}", counter, 12345678)).unwrap();
+    assert_eq!(counter.0.get(), 1);
+    assert_eq!(w.0, ["x".repeat(100) + "12345678"]);
+
+    let mut w = Chunks::default();
+    assert_eq!(
+        block_on(uwrite_async!(w, "{:?}{:?}", counter, counter)),
+        Err(AsyncWriteError::Overflow)
+    );
+    assert!(w.0.is_empty());
+
+    // unless the caller asks for a larger buffer
+    block_on(uwrite_async!(
+        w,
+        capacity = 256,
+        "{:?}{:?}",
+        counter,
+        counter
+    ))
+    .unwrap();
+    assert_eq!(w.0, ["x".repeat(200)]);
+}
+
+#[test]
+fn core_types() {
+    use core::{
+        cell::{Cell, RefCell},
+        cmp::{Ordering, Reverse},
+        marker::PhantomData,
+        mem::ManuallyDrop,
+        num::{Saturating, Wrapping},
+        ops::RangeInclusive,
+        pin::Pin,
+        ptr::NonNull,
+        sync::atomic::{AtomicBool, AtomicI8, AtomicPtr, AtomicU64, AtomicUsize},
+    };
+
+    cmp!(
+        "{:?} {:?} {:?}",
+        Ordering::Less,
+        Ordering::Equal,
+        Ordering::Greater
+    );
+    cmp!("{:?} {:#?}", Reverse(1), Reverse((1, 2)));
+    cmp!("{:?}", PhantomData::<Vec<i32>>);
+
+    cmp!("{:?} {:#?}", Cell::new(1), Cell::new((1, 2)));
+    let cell = RefCell::new(2);
+    cmp!("{:?}", cell);
+    {
+        let _shared = cell.borrow();
+        cmp!("{:?}", cell);
+    }
+    {
+        let _exclusive = cell.borrow_mut();
+        cmp!("{:?} {:#?}", cell, cell);
+    }
+
+    cmp!(
+        "{:?} {} {:?} {}",
+        Wrapping(3),
+        Wrapping(3u8),
+        Saturating(-4),
+        Saturating(-4)
+    );
+
+    let mut exhausted = 1..=2;
+    exhausted.by_ref().for_each(drop);
+    let empty = RangeInclusive::new(5, 1);
+    cmp!("{:?} {:?} {:?} {:?}", 1..2, 1..=2, exhausted, empty);
+
+    // no `PartialOrd` bound on the index type
+    #[derive(Debug, uDebug)]
+    struct Index(u8);
+    cmp!("{:?}", Index(1)..=Index(2));
+
+    cmp!("{:?} {:?} {:?} {:?} {:#?}", 3.., ..4, ..=5, .., (1..2, 3..));
+
+    let x = 5;
+    cmp!("{:?} {}", Pin::new(&x), Pin::new(&x));
+    let mut y = 0u8;
+    cmp!("{:?}", NonNull::from(&mut y));
+
+    cmp!(
+        "{:?} {:?} {:?} {:?}",
+        AtomicBool::new(true),
+        AtomicI8::new(-1),
+        AtomicU64::new(u64::MAX),
+        AtomicUsize::new(7)
+    );
+    cmp!("{:?}", AtomicPtr::new(&mut y));
+
+    // unlike `core::fmt`, which also prints an internal `MaybeDangling(..)` wrapper on recent
+    // versions
+    assert_eq!(
+        uformat!("{:?} {:#?}", ManuallyDrop::new(1), ManuallyDrop::new(1)).unwrap(),
+        "ManuallyDrop { value: 1 } ManuallyDrop {\n    value: 1,\n}"
+    );
+
+    fn never() -> Result<(), Infallible> {
+        Ok(())
+    }
+    // an `Infallible` can't be constructed so it only needs to type check
+    cmp!("{:?}", never());
+}
+
+#[test]
+fn alloc_types() {
+    use std::{
+        borrow::Cow,
+        collections::{BinaryHeap, LinkedList, VecDeque},
+        rc::{self, Rc},
+        sync::{self, Arc},
+    };
+
+    cmp!("{:?} {}", Rc::new([1, 2]), Rc::<str>::from("rc"));
+    cmp!("{:#?} {}", Arc::new((1,)), Arc::<str>::from("arc"));
+    cmp!("{:?} {}", Box::<[i32]>::from([1]), Box::<str>::from("box"));
+
+    let rc = Rc::new(1);
+    let arc = Arc::new(1);
+    cmp!("{:?} {:?}", Rc::downgrade(&rc), rc::Weak::<i32>::new());
+    cmp!("{:?} {:?}", Arc::downgrade(&arc), sync::Weak::<i32>::new());
+
+    cmp!(
+        "{} {}",
+        Cow::Borrowed("borrowed"),
+        Cow::<str>::Owned("owned".into())
+    );
+    cmp!(
+        "{:?} {:?}",
+        Cow::Borrowed(&[1, 2][..]),
+        Cow::<[i32]>::Owned(vec![3])
+    );
+
+    let mut deque = VecDeque::from([2, 3]);
+    deque.push_front(1);
+    cmp!("{:?} {:#?}", deque, VecDeque::<i32>::new());
+    cmp!("{:?}", BinaryHeap::from([1, 5, 2, 4]));
+    cmp!("{:#?}", LinkedList::from([1, 2]));
+}
+
+#[test]
+fn location() {
+    let here = core::panic::Location::caller();
+    cmp!("{}", here);
+}
+
+#[test]
+fn errors() {
+    use core::{alloc::Layout, num::NonZeroU8};
+
+    for s in ["", "x", "999", "-999"] {
+        let e = s.parse::<i8>().unwrap_err();
+        cmp!("{} {:?} {:#?}", e, e, e);
+    }
+    let e = "0".parse::<NonZeroU8>().unwrap_err();
+    cmp!("{} {:?}", e, e);
+
+    // NOTE newer versions of `std` also print the kind of error, which is not accessible on stable
+    let e = u8::try_from(300).unwrap_err();
+    cmp!("{}", e);
+    assert_eq!(
+        uformat!("{:?} {:#?}", e, e).unwrap(),
+        "TryFromIntError(()) TryFromIntError(\n    (),\n)"
+    );
+
+    for bytes in [&b"a\xff"[..], b"ab\xe2\x82", b"\xe2\x82a"] {
+        let e = core::str::from_utf8(bytes).unwrap_err();
+        cmp!("{} {:?} {:#?}", e, e, e);
+    }
+
+    let e = char::try_from(0xd800u32).unwrap_err();
+    cmp!("{} {:?}", e, e);
+
+    let e = "x".parse::<bool>().unwrap_err();
+    cmp!("{} {:?} {:#?}", e, e, e);
+
+    let e = <[u8; 2]>::try_from(&[1][..]).unwrap_err();
+    cmp!("{} {:?}", e, e);
+
+    let e = Layout::from_size_align(1, 3).unwrap_err();
+    cmp!("{} {:?}", e, e);
+}
+
+#[test]
+fn error_report() {
+    use ufmt::{
+        derive::uError,
+        error::{uError, Report},
+    };
+
+    #[derive(uDebug, uError)]
+    #[uerror("timed out after {}ms", ms)]
+    struct Timeout {
+        ms: u32,
+    }
+
+    #[derive(uDebug, uError)]
+    enum Error<E> {
+        #[uerror("bus error")]
+        Bus(#[source] E),
+        #[uerror("failed to parse {:?}", bytes)]
+        Parse {
+            bytes: [u8; 2],
+            #[source]
+            source: Box<ParseIntError>,
+        },
+        #[uerror("unsupported")]
+        Unsupported,
+    }
+
+    #[derive(uDebug, uError)]
+    enum Never {}
+
+    let e = Error::Bus(Timeout { ms: 10 });
+    assert_eq!(uformat!("{}", e).unwrap(), "bus error");
+    assert_eq!(
+        uformat!("{}", Report::new(&e)).unwrap(),
+        "error: bus error\ncaused by: timed out after 10ms"
+    );
+    assert_eq!(
+        uformat!("{:?}", Report::from(e)).unwrap(),
+        "error: bus error\ncaused by: timed out after 10ms"
+    );
+
+    let e = Error::<Timeout>::Parse {
+        bytes: *b"1x",
+        source: Box::new("1x".parse::<u8>().unwrap_err()),
+    };
+    assert_eq!(
+        uformat!("{}", Report::new(e)).unwrap(),
+        "error: failed to parse [49, 120]\ncaused by: invalid digit found in string"
+    );
+
+    let e = Error::<Never>::Unsupported;
+    assert!(e.source(Ignore).is_none());
+    assert_eq!(
+        uformat!("{}", Report::new(e)).unwrap(),
+        "error: unsupported"
+    );
+
+    struct Ignore;
+
+    impl ufmt::error::SourceVisitor for Ignore {
+        type Output = ();
+
+        fn visit<E>(self, _: &E)
+        where
+            E: uError + ?Sized,
+        {
+        }
+    }
+}
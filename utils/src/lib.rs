@@ -9,10 +9,10 @@
 #![deny(warnings)]
 #![no_std]
 
-use core::{convert::Infallible, fmt, str};
+use core::{cmp, convert::Infallible, fmt, marker::PhantomData, mem::MaybeUninit, str};
 
 use heapless::String;
-use ufmt_write::uWrite;
+use ufmt_write::{uWrite, uWriteBytes};
 
 macro_rules! assume_unreachable {
     () => {
@@ -57,9 +57,42 @@ where
         let _ = self.writer.write_str(s);
         Ok(())
     }
+
+    fn flush(&mut self) -> Result<(), Infallible> {
+        let _ = self.writer.flush();
+        Ok(())
+    }
+}
+
+impl<W> uWriteBytes for Ignore<W>
+where
+    W: uWriteBytes,
+{
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Infallible> {
+        let _ = self.writer.write_bytes(bytes);
+        Ok(())
+    }
 }
 
 /// A write adapter that buffers writes and automatically flushes on newlines
+///
+/// A newline only writes the buffered line into the underlying writer, like `std`'s `LineWriter`;
+/// [`uWrite::flush`] also flushes the underlying writer.
+///
+/// ```
+/// use ufmt::uwrite;
+/// use ufmt_utils::LineBuffered;
+/// use ufmt_write::uWrite;
+///
+/// fn log<W: uWrite>(w: &mut W, record: u32) -> Result<(), W::Error> {
+///     uwrite!(w, "record {}", record)?;
+///     w.flush()
+/// }
+///
+/// let mut w = LineBuffered::<_, 64>::new(String::new());
+/// log(&mut w, 1).unwrap();
+/// assert_eq!(w.free(), "record 1");
+/// ```
 pub struct LineBuffered<W, const N: usize>
 where
     W: uWrite,
@@ -80,11 +113,10 @@ where
         }
     }
 
-    /// Flushes the contents of the buffer
+    /// Flushes the contents of the buffer and then the underlying writer
     pub fn flush(&mut self) -> Result<(), W::Error> {
-        let ret = self.writer.write_str(&self.buffer);
-        self.buffer.clear();
-        ret
+        self.flush_buffer()?;
+        self.writer.flush()
     }
 
     /// Destroys the adapter and returns the underlying writer
@@ -92,10 +124,16 @@ where
         self.writer
     }
 
+    fn flush_buffer(&mut self) -> Result<(), W::Error> {
+        let ret = self.writer.write_str(&self.buffer);
+        self.buffer.clear();
+        ret
+    }
+
     fn push_str(&mut self, s: &str) -> Result<(), W::Error> {
-        let len = s.as_bytes().len();
+        let len = s.len();
         if self.buffer.len() + len > self.buffer.capacity() {
-            self.flush()?;
+            self.flush_buffer()?;
         }
 
         if len > self.buffer.capacity() {
@@ -123,7 +161,7 @@ where
                 .unwrap_or_else(|| unsafe { assume_unreachable!() });
 
             self.push_str(line)?;
-            self.flush()?;
+            self.flush_buffer()?;
 
             s = s
                 .get(pos + 1..)
@@ -132,6 +170,679 @@ where
 
         self.push_str(s)
     }
+
+    #[inline(always)]
+    fn flush(&mut self) -> Result<(), W::Error> {
+        LineBuffered::flush(self)
+    }
+}
+
+impl<W, const N: usize> uWriteBytes for LineBuffered<W, N>
+where
+    W: uWriteBytes,
+{
+    // raw bytes are not buffered; the buffered text is written out first to preserve ordering
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), W::Error> {
+        self.flush_buffer()?;
+        self.writer.write_bytes(bytes)
+    }
+}
+
+/// A write adapter that writes a prefix at the start of every line
+///
+/// The prefix is produced by a callback that writes into the underlying writer, e.g. an uptime
+/// counter or a module tag. It's written lazily, right before the first character of each line, so
+/// a trailing newline does not leave a dangling prefix behind.
+///
+/// ```
+/// use ufmt::uwrite;
+/// use ufmt_utils::LinePrefixed;
+///
+/// let mut uptime = 41;
+/// let mut w = LinePrefixed::new(String::new(), |w: &mut String| {
+///     uptime += 1;
+///     uwrite!(w, "[{}] ", uptime)
+/// });
+/// uwrite!(w, "{:#?}\n", (1, 2)).unwrap();
+/// assert_eq!(w.free(), "[42] (\n[43]     1,\n[44]     2,\n[45] )\n");
+/// ```
+pub struct LinePrefixed<W, F>
+where
+    W: uWrite,
+    F: FnMut(&mut W) -> Result<(), W::Error>,
+{
+    at_line_start: bool,
+    prefix: F,
+    writer: W,
+}
+
+impl<W, F> LinePrefixed<W, F>
+where
+    W: uWrite,
+    F: FnMut(&mut W) -> Result<(), W::Error>,
+{
+    /// Creates a new `LinePrefixed` adapter
+    pub fn new(writer: W, prefix: F) -> Self {
+        Self {
+            at_line_start: true,
+            prefix,
+            writer,
+        }
+    }
+
+    /// Destroys the adapter and returns the underlying writer
+    pub fn free(self) -> W {
+        self.writer
+    }
+
+    // writes the prefix if `self` is at the start of a line; returns the length of the line's
+    // first piece, which is the whole `bytes` if they contain no newline
+    fn start(&mut self, bytes: &[u8]) -> Result<usize, W::Error> {
+        if self.at_line_start {
+            (self.prefix)(&mut self.writer)?;
+            self.at_line_start = false;
+        }
+
+        Ok(match bytes.iter().position(|b| *b == b'\n') {
+            Some(pos) => {
+                self.at_line_start = true;
+                pos + 1
+            }
+            None => bytes.len(),
+        })
+    }
+}
+
+impl<W, F> uWrite for LinePrefixed<W, F>
+where
+    W: uWrite,
+    F: FnMut(&mut W) -> Result<(), W::Error>,
+{
+    type Error = W::Error;
+
+    fn write_str(&mut self, mut s: &str) -> Result<(), W::Error> {
+        while !s.is_empty() {
+            let end = self.start(s.as_bytes())?;
+            let line = s
+                .get(..end)
+                .unwrap_or_else(|| unsafe { assume_unreachable!() });
+
+            self.writer.write_str(line)?;
+
+            s = s
+                .get(end..)
+                .unwrap_or_else(|| unsafe { assume_unreachable!() });
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), W::Error> {
+        self.writer.flush()
+    }
+}
+
+impl<W, F> uWriteBytes for LinePrefixed<W, F>
+where
+    W: uWriteBytes,
+    F: FnMut(&mut W) -> Result<(), W::Error>,
+{
+    fn write_bytes(&mut self, mut bytes: &[u8]) -> Result<(), W::Error> {
+        while !bytes.is_empty() {
+            let end = self.start(bytes)?;
+            let line = bytes
+                .get(..end)
+                .unwrap_or_else(|| unsafe { assume_unreachable!() });
+
+            self.writer.write_bytes(line)?;
+
+            bytes = bytes
+                .get(end..)
+                .unwrap_or_else(|| unsafe { assume_unreachable!() });
+        }
+
+        Ok(())
+    }
+}
+
+/// A circular log that keeps the newest (up to) `N` bytes written into it
+///
+/// Writes never fail; when the buffer is full the oldest text is overwritten. The text is stored so
+/// that it can be read back as two `&str` halves, the older one first, without splitting a UTF-8
+/// character. To do so a few of the oldest bytes may be dropped earlier than strictly necessary.
+///
+/// ```
+/// use ufmt::uwrite;
+/// use ufmt_utils::RingBuffer;
+///
+/// let mut log = RingBuffer::<16>::new();
+/// for i in 0..10 {
+///     uwrite!(log, "{} ", i).unwrap();
+/// }
+///
+/// let (older, newer) = log.as_strs();
+/// assert_eq!([older, newer].concat(), "2 3 4 5 6 7 8 9 ");
+/// assert_eq!(log.iter().collect::<String>(), "2 3 4 5 6 7 8 9 ");
+/// ```
+///
+/// The buffer can be placed in memory that's not initialized on start up so that its contents
+/// survive a reset; see [`RingBuffer::from_uninit`].
+pub struct RingBuffer<const N: usize> {
+    // set by `new`; used to tell apart a buffer that survived a reset from garbage
+    magic: u32,
+    // where the next byte will be written
+    head: usize,
+    // end of the older half, which starts at `head`; the older half is empty if `head >= wrap`
+    wrap: usize,
+    buffer: [u8; N],
+}
+
+const RING_BUFFER_MAGIC: u32 = 0x7566_6d74;
+
+impl<const N: usize> RingBuffer<N> {
+    /// Creates an empty `RingBuffer`
+    pub const fn new() -> Self {
+        Self {
+            magic: RING_BUFFER_MAGIC,
+            head: 0,
+            wrap: 0,
+            buffer: [0; N],
+        }
+    }
+
+    /// Reuses a `RingBuffer` that lives in memory that's not initialized on start up
+    ///
+    /// If the memory holds a `RingBuffer` that was in use before a reset its contents are kept;
+    /// otherwise (e.g. on a cold boot) an empty `RingBuffer` is written into it.
+    ///
+    /// ``` no_run
+    /// use core::{mem::MaybeUninit, ptr};
+    ///
+    /// use ufmt_utils::RingBuffer;
+    ///
+    /// #[link_section = ".uninit.LOG"]
+    /// static mut LOG: MaybeUninit<RingBuffer<1024>> = MaybeUninit::uninit();
+    ///
+    /// // on start up
+    /// let log = unsafe { RingBuffer::from_uninit(&mut *ptr::addr_of_mut!(LOG)) };
+    /// let (older, newer) = log.as_strs();
+    /// // .. report the log of the previous run ..
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The memory must hold *some* bytes, i.e. it must have been written at least once, e.g. by
+    /// the hardware or the start up code, since the program started.
+    pub unsafe fn from_uninit(memory: &mut MaybeUninit<Self>) -> &mut Self {
+        let this = memory.as_mut_ptr();
+        if (*this).magic != RING_BUFFER_MAGIC || (*this).head > N || (*this).wrap > N {
+            this.write(Self::new());
+        }
+
+        &mut *this
+    }
+
+    /// Returns the retained text as two halves, the older one first
+    pub fn as_strs(&self) -> (&str, &str) {
+        // the first character of the older half may have been partially overwritten
+        let mut start = self.head;
+        while self.buffer.get(start).is_some_and(|b| b & 0xc0 == 0x80) {
+            start += 1;
+        }
+
+        let older = if start < self.wrap {
+            self.buffer.get(start..self.wrap).unwrap_or(&[])
+        } else {
+            &[]
+        };
+        let newer = self.buffer.get(..self.head).unwrap_or(&[]);
+
+        // the contents are only untrusted when the buffer survived a reset
+        (valid_prefix(older), valid_prefix(newer))
+    }
+
+    /// Returns an iterator over the (non-empty) halves of the retained text, the older one first
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        let (older, newer) = self.as_strs();
+        [older, newer].into_iter().filter(|half| !half.is_empty())
+    }
+
+    /// Discards the retained text
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.wrap = 0;
+    }
+}
+
+impl<const N: usize> Default for RingBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> uWrite for RingBuffer<N> {
+    type Error = Infallible;
+
+    fn write_str(&mut self, mut s: &str) -> Result<(), Infallible> {
+        while !s.is_empty() {
+            let mut end = cmp::min(N - self.head, s.len());
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+
+            if end == 0 {
+                if self.head == 0 {
+                    // the character doesn't fit in the buffer at all
+                    let len = s.chars().next().map_or(s.len(), char::len_utf8);
+                    s = s.get(len..).unwrap_or("");
+                } else {
+                    // start over; the text that was just written becomes the older half
+                    self.wrap = self.head;
+                    self.head = 0;
+                }
+
+                continue;
+            }
+
+            if let (Some(dst), Some(src)) = (
+                self.buffer.get_mut(self.head..self.head + end),
+                s.as_bytes().get(..end),
+            ) {
+                dst.copy_from_slice(src);
+            }
+            self.head += end;
+
+            s = s
+                .get(end..)
+                .unwrap_or_else(|| unsafe { assume_unreachable!() });
+        }
+
+        Ok(())
+    }
+}
+
+fn valid_prefix(bytes: &[u8]) -> &str {
+    match str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => bytes
+            .get(..e.valid_up_to())
+            .and_then(|bytes| str::from_utf8(bytes).ok())
+            .unwrap_or(""),
+    }
+}
+
+/// A write adapter that writes everything into several writers
+///
+/// The writers are passed as a tuple of 2 to 6 elements, e.g. `Tee::new((uart, crash_log))`, and
+/// are written in order. How their errors are combined is chosen with the policy `P`: [`FailFast`]
+/// (the default), [`BestEffort`] or [`PerSink`].
+///
+/// ```
+/// use ufmt::uwrite;
+/// use ufmt_utils::{BestEffort, Tee, Truncate};
+///
+/// let mut w = Tee::new((String::new(), Vec::new()));
+/// uwrite!(w, "x = {}", 42).unwrap();
+/// let (text, bytes) = w.free();
+/// assert_eq!(text, "x = 42");
+/// assert_eq!(bytes, b"x = 42");
+///
+/// let mut w = Tee::with_policy((String::new(), Truncate::<_, 4>::new(String::new())), BestEffort);
+/// uwrite!(w, "{}", "abcdef").unwrap();
+/// let (full, truncated) = w.free();
+/// assert_eq!(full, "abcdef");
+/// assert_eq!(truncated.free(), "a...");
+/// ```
+pub struct Tee<S, P = FailFast> {
+    policy: PhantomData<P>,
+    sinks: S,
+}
+
+impl<S> Tee<S>
+where
+    S: UnstableSinks,
+{
+    /// Creates a new `Tee` adapter that uses the [`FailFast`] policy
+    pub fn new(sinks: S) -> Self {
+        Self::with_policy(sinks, FailFast)
+    }
+}
+
+impl<S, P> Tee<S, P>
+where
+    S: UnstableSinks,
+    P: TeePolicy<S>,
+{
+    /// Creates a new `Tee` adapter that uses the given policy
+    pub fn with_policy(sinks: S, _policy: P) -> Self {
+        Self {
+            policy: PhantomData,
+            sinks,
+        }
+    }
+
+    /// Destroys the adapter and returns the underlying writers
+    pub fn free(self) -> S {
+        self.sinks
+    }
+}
+
+impl<S, P> uWrite for Tee<S, P>
+where
+    S: UnstableSinks,
+    P: TeePolicy<S>,
+{
+    type Error = P::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), P::Error> {
+        P::finish(self.sinks.apply(UnstableOp::Str(s), P::FAIL_FAST))
+    }
+
+    fn flush(&mut self) -> Result<(), P::Error> {
+        P::finish(self.sinks.apply(UnstableOp::Flush, P::FAIL_FAST))
+    }
+}
+
+impl<S, P> uWriteBytes for Tee<S, P>
+where
+    S: UnstableByteSinks,
+    P: TeePolicy<S>,
+{
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), P::Error> {
+        P::finish(self.sinks.apply_bytes(bytes, P::FAIL_FAST))
+    }
+}
+
+/// How [`Tee`] combines the results of its writers
+pub trait TeePolicy<S>
+where
+    S: UnstableSinks,
+{
+    /// The error of the `Tee` adapter
+    type Error;
+
+    #[doc(hidden)]
+    const FAIL_FAST: bool;
+
+    #[doc(hidden)]
+    fn finish(result: Result<(), S::Errors>) -> Result<(), Self::Error>;
+}
+
+/// Stops at the first writer that fails
+///
+/// The error is a tuple of `Option`-al errors, one per writer, where only the failed writer's
+/// element is `Some`. The writers that follow it are not written.
+pub struct FailFast;
+
+impl<S> TeePolicy<S> for FailFast
+where
+    S: UnstableSinks,
+{
+    type Error = S::Errors;
+
+    const FAIL_FAST: bool = true;
+
+    fn finish(result: Result<(), S::Errors>) -> Result<(), S::Errors> {
+        result
+    }
+}
+
+/// Writes into every writer and ignores their errors
+pub struct BestEffort;
+
+impl<S> TeePolicy<S> for BestEffort
+where
+    S: UnstableSinks,
+{
+    type Error = Infallible;
+
+    const FAIL_FAST: bool = false;
+
+    fn finish(_: Result<(), S::Errors>) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+/// Writes into every writer and reports the error of each one that failed
+///
+/// The error is a tuple of `Option`-al errors, one per writer.
+pub struct PerSink;
+
+impl<S> TeePolicy<S> for PerSink
+where
+    S: UnstableSinks,
+{
+    type Error = S::Errors;
+
+    const FAIL_FAST: bool = false;
+
+    fn finish(result: Result<(), S::Errors>) -> Result<(), S::Errors> {
+        result
+    }
+}
+
+// Implementation detail of `Tee`
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub enum UnstableOp<'a> {
+    Str(&'a str),
+    Flush,
+}
+
+impl UnstableOp<'_> {
+    fn apply<W>(self, w: &mut W) -> Result<(), W::Error>
+    where
+        W: uWrite,
+    {
+        match self {
+            UnstableOp::Str(s) => w.write_str(s),
+            UnstableOp::Flush => w.flush(),
+        }
+    }
+}
+
+// Implementation detail of `Tee`; implemented for tuples of writers
+#[doc(hidden)]
+pub trait UnstableSinks {
+    type Errors;
+
+    fn apply(&mut self, op: UnstableOp<'_>, fail_fast: bool) -> Result<(), Self::Errors>;
+}
+
+// Implementation detail of `Tee`; implemented for tuples of byte-oriented writers
+#[doc(hidden)]
+pub trait UnstableByteSinks: UnstableSinks {
+    fn apply_bytes(&mut self, bytes: &[u8], fail_fast: bool) -> Result<(), Self::Errors>;
+}
+
+// runs `$op` on every writer in `$sinks`, which is bound to `$w`, and collects the errors
+macro_rules! tee {
+    ($sinks:ident, $fail_fast:ident, |$w:ident| $op:expr, $($W:ident . $i:tt),+) => {{
+        let mut errors = ($(None::<$W::Error>,)+);
+        let mut failed = false;
+        $(
+            if !(failed && $fail_fast) {
+                let $w = &mut $sinks.$i;
+                if let Err(e) = $op {
+                    errors.$i = Some(e);
+                    failed = true;
+                }
+            }
+        )+
+
+        if failed {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }};
+}
+
+macro_rules! sinks {
+    ($($W:ident . $i:tt),+) => {
+        impl<$($W),+> UnstableSinks for ($($W,)+)
+        where
+            $($W: uWrite,)+
+        {
+            type Errors = ($(Option<$W::Error>,)+);
+
+            fn apply(&mut self, op: UnstableOp<'_>, fail_fast: bool) -> Result<(), Self::Errors> {
+                tee!(self, fail_fast, |w| op.apply(w), $($W.$i),+)
+            }
+        }
+
+        impl<$($W),+> UnstableByteSinks for ($($W,)+)
+        where
+            $($W: uWriteBytes,)+
+        {
+            fn apply_bytes(&mut self, bytes: &[u8], fail_fast: bool) -> Result<(), Self::Errors> {
+                tee!(self, fail_fast, |w| w.write_bytes(bytes), $($W.$i),+)
+            }
+        }
+    }
+}
+
+sinks!(A.0, B.1);
+sinks!(A.0, B.1, C.2);
+sinks!(A.0, B.1, C.2, D.3);
+sinks!(A.0, B.1, C.2, D.3, E.4);
+sinks!(A.0, B.1, C.2, D.3, E.4, F.5);
+
+/// A write adapter that writes at most `N` bytes and marks the output when it had to cut it short
+///
+/// The marker counts towards the `N` bytes so the adapter can wrap fixed-capacity writers. As the
+/// adapter can't know whether more text will follow, at most `N - marker.len()` bytes of text are
+/// written; the text is cut on a UTF-8 character boundary, the marker is appended and the rest of
+/// the message is discarded without error. A marker longer than `N` bytes is itself cut to fit.
+///
+/// ```
+/// use ufmt::uwrite;
+/// use ufmt_utils::Truncate;
+///
+/// let mut w = Truncate::<_, 8>::new(String::new());
+/// uwrite!(w, "temperature: {}", 25).unwrap();
+/// assert!(w.is_truncated());
+/// assert_eq!(w.free(), "tempe...");
+///
+/// let mut w = Truncate::<_, 8>::with_marker(String::new(), "~");
+/// uwrite!(w, "{}", 'µ').unwrap();
+/// uwrite!(w, "{}", "ünïcödé").unwrap();
+/// assert_eq!(w.free(), "µünï~");
+/// ```
+pub struct Truncate<W, const N: usize>
+where
+    W: uWrite,
+{
+    // how much text fits next to the marker
+    limit: usize,
+    marker: &'static str,
+    truncated: bool,
+    written: usize,
+    writer: W,
+}
+
+impl<W, const N: usize> Truncate<W, N>
+where
+    W: uWrite,
+{
+    /// Creates a new `Truncate` adapter that uses `...` as the marker
+    pub fn new(writer: W) -> Self {
+        Self::with_marker(writer, "...")
+    }
+
+    /// Creates a new `Truncate` adapter that uses the given marker
+    pub fn with_marker(writer: W, marker: &'static str) -> Self {
+        let mut end = cmp::min(marker.len(), N);
+        while !marker.is_char_boundary(end) {
+            end -= 1;
+        }
+        let marker = marker.get(..end).unwrap_or("");
+
+        Self {
+            limit: N - marker.len(),
+            marker,
+            truncated: false,
+            written: 0,
+            writer,
+        }
+    }
+
+    /// Returns `true` if part of the output was discarded
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Destroys the adapter and returns the underlying writer
+    pub fn free(self) -> W {
+        self.writer
+    }
+
+    fn truncate(&mut self) -> Result<(), W::Error> {
+        self.truncated = true;
+        self.written = self.limit;
+        self.writer.write_str(self.marker)
+    }
+}
+
+impl<W, const N: usize> uWrite for Truncate<W, N>
+where
+    W: uWrite,
+{
+    type Error = W::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), W::Error> {
+        if self.truncated || s.is_empty() {
+            return Ok(());
+        }
+
+        let free = self.limit - self.written;
+        if s.len() <= free {
+            self.written += s.len();
+            return self.writer.write_str(s);
+        }
+
+        let mut end = free;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let head = s
+            .get(..end)
+            .unwrap_or_else(|| unsafe { assume_unreachable!() });
+        if !head.is_empty() {
+            self.writer.write_str(head)?;
+        }
+        self.truncate()
+    }
+
+    fn flush(&mut self) -> Result<(), W::Error> {
+        self.writer.flush()
+    }
+}
+
+impl<W, const N: usize> uWriteBytes for Truncate<W, N>
+where
+    W: uWriteBytes,
+{
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), W::Error> {
+        if self.truncated || bytes.is_empty() {
+            return Ok(());
+        }
+
+        let free = self.limit - self.written;
+        if bytes.len() <= free {
+            self.written += bytes.len();
+            return self.writer.write_bytes(bytes);
+        }
+
+        let head = bytes
+            .get(..free)
+            .unwrap_or_else(|| unsafe { assume_unreachable!() });
+        if !head.is_empty() {
+            self.writer.write_bytes(head)?;
+        }
+        self.truncate()
+    }
 }
 
 /// An adapter struct allowing to use `ufmt` on types which implement `core::fmt::Write`
@@ -166,3 +877,191 @@ where
         self.0.write_str(s)
     }
 }
+
+macro_rules! escape {
+    ($(#[$attr:meta])* $Escape:ident, $escape:ident) => {
+        $(#[$attr])*
+        pub struct $Escape<W>
+        where
+            W: uWrite,
+        {
+            writer: W,
+        }
+
+        impl<W> $Escape<W>
+        where
+            W: uWrite,
+        {
+            #[doc = concat!("Creates a new `", stringify!($Escape), "` adapter")]
+            pub fn new(writer: W) -> Self {
+                Self { writer }
+            }
+
+            /// Destroys the adapter and returns the underlying writer
+            pub fn free(self) -> W {
+                self.writer
+            }
+        }
+
+        impl<W> uWrite for $Escape<W>
+        where
+            W: uWrite,
+        {
+            type Error = W::Error;
+
+            fn write_str(&mut self, s: &str) -> Result<(), W::Error> {
+                write_escaped(&mut self.writer, s, $escape)
+            }
+
+            fn flush(&mut self) -> Result<(), W::Error> {
+                self.writer.flush()
+            }
+        }
+    };
+}
+
+escape!(
+    /// A write adapter that escapes text for use inside a C string literal
+    ///
+    /// Backslashes and double quotes are escaped; control characters are written as `\n`, `\r`,
+    /// `\t` or as octal escapes, which unlike `\x` escapes can't swallow the characters that follow.
+    ///
+    /// ```
+    /// use ufmt::uwrite;
+    /// use ufmt_utils::CEscape;
+    ///
+    /// let mut w = CEscape::new(String::new());
+    /// uwrite!(w, "{}", "\"C:\\tmp\"\t\x1b7").unwrap();
+    /// assert_eq!(w.free(), r#"\"C:\\tmp\"\t\0337"#);
+    /// ```
+    CEscape,
+    escape_c
+);
+
+escape!(
+    /// A write adapter that escapes text for use inside a double-quoted CSV field
+    ///
+    /// Double quotes are doubled; everything else, line breaks included, is written as it is.
+    ///
+    /// ```
+    /// use ufmt::uwrite;
+    /// use ufmt_utils::CsvEscape;
+    ///
+    /// let mut w = CsvEscape::new(String::new());
+    /// uwrite!(w, "{}", "12\" screen").unwrap();
+    /// assert_eq!(w.free(), "12\"\" screen");
+    /// ```
+    CsvEscape,
+    escape_csv
+);
+
+escape!(
+    /// A write adapter that escapes text for use inside a JSON string
+    ///
+    /// Backslashes, double quotes and control characters are escaped; the latter as `\n`, `\r`,
+    /// `\t`, `\b`, `\f` or `\u00XX`.
+    ///
+    /// ```
+    /// use ufmt::uwrite;
+    /// use ufmt_utils::JsonEscape;
+    ///
+    /// let mut w = JsonEscape::new(String::new());
+    /// uwrite!(w, "{}", "say \"hi\"\n\x00").unwrap();
+    /// assert_eq!(w.free(), r#"say \"hi\"\n\u0000"#);
+    /// ```
+    JsonEscape,
+    escape_json
+);
+
+// writes `s` replacing the bytes for which `escape` returns an escape sequence; only ASCII bytes are
+// escaped so `s` is always split on character boundaries
+fn write_escaped<W>(
+    writer: &mut W,
+    s: &str,
+    escape: fn(u8, &mut [u8; 6]) -> Option<&str>,
+) -> Result<(), W::Error>
+where
+    W: uWrite,
+{
+    let mut buf = [0; 6];
+    let mut start = 0;
+    for (pos, byte) in s.bytes().enumerate() {
+        if let Some(sequence) = escape(byte, &mut buf) {
+            let run = s
+                .get(start..pos)
+                .unwrap_or_else(|| unsafe { assume_unreachable!() });
+            if !run.is_empty() {
+                writer.write_str(run)?;
+            }
+            writer.write_str(sequence)?;
+
+            start = pos + 1;
+        }
+    }
+
+    let run = s
+        .get(start..)
+        .unwrap_or_else(|| unsafe { assume_unreachable!() });
+    if run.is_empty() {
+        Ok(())
+    } else {
+        writer.write_str(run)
+    }
+}
+
+fn escape_c(byte: u8, buf: &mut [u8; 6]) -> Option<&str> {
+    Some(match byte {
+        b'\\' => "\\\\",
+        b'"' => "\\\"",
+        b'\n' => "\\n",
+        b'\r' => "\\r",
+        b'\t' => "\\t",
+        0..=0x1f | 0x7f => {
+            *buf = *b"\\000\0\0";
+            buf[1] += byte >> 6;
+            buf[2] += byte >> 3 & 7;
+            buf[3] += byte & 7;
+            ascii(buf.get(..4).unwrap_or(&[]))
+        }
+        _ => return None,
+    })
+}
+
+fn escape_csv(byte: u8, _: &mut [u8; 6]) -> Option<&str> {
+    if byte == b'"' {
+        Some("\"\"")
+    } else {
+        None
+    }
+}
+
+fn escape_json(byte: u8, buf: &mut [u8; 6]) -> Option<&str> {
+    Some(match byte {
+        b'\\' => "\\\\",
+        b'"' => "\\\"",
+        b'\n' => "\\n",
+        b'\r' => "\\r",
+        b'\t' => "\\t",
+        0x08 => "\\b",
+        0x0c => "\\f",
+        0..=0x1f => {
+            *buf = *b"\\u0000";
+            buf[4] += byte >> 4;
+            buf[5] = hex_digit(byte & 0xf);
+            ascii(buf)
+        }
+        _ => return None,
+    })
+}
+
+fn hex_digit(n: u8) -> u8 {
+    if n < 10 {
+        b'0' + n
+    } else {
+        b'a' + (n - 10)
+    }
+}
+
+fn ascii(bytes: &[u8]) -> &str {
+    str::from_utf8(bytes).unwrap_or_else(|_| unsafe { assume_unreachable!() })
+}
@@ -0,0 +1,283 @@
+//! Exercises every adapter through every `uWrite` method; also run under miri in CI
+
+use core::{cmp, convert::Infallible};
+
+use ufmt::uwrite;
+use ufmt_utils::{
+    BestEffort, CEscape, CsvEscape, Ignore, JsonEscape, LineBuffered, LinePrefixed, PerSink,
+    RingBuffer, Tee, Truncate, WriteAdapter,
+};
+use ufmt_write::{uWrite, uWriteBytes};
+
+// a writer that only implements the required method; everything else is provided
+#[derive(Default)]
+struct Log {
+    text: String,
+    writes: usize,
+}
+
+impl uWrite for Log {
+    type Error = Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.text.push_str(s);
+        self.writes += 1;
+        Ok(())
+    }
+}
+
+// always fails
+struct Broken;
+
+impl uWrite for Broken {
+    type Error = ();
+
+    fn write_str(&mut self, _: &str) -> Result<(), ()> {
+        Err(())
+    }
+}
+
+impl uWriteBytes for Broken {
+    fn write_bytes(&mut self, _: &[u8]) -> Result<(), ()> {
+        Err(())
+    }
+}
+
+#[test]
+fn provided_methods() {
+    let mut log = Log::default();
+    for c in ['a', 'é', '€', '🦀'] {
+        log.write_char(c).unwrap();
+    }
+    log.flush().unwrap();
+    assert_eq!(log.text, "aé€🦀");
+
+    let mut v = Vec::new();
+    uwrite!(v, "{}{:#06x}", 'μ', 42).unwrap();
+    v.write_bytes(b"\xff").unwrap();
+    assert_eq!(v, b"\xce\xbc0x002a\xff");
+}
+
+#[test]
+fn escape() {
+    let input = "a\"\\\n\r\t\x08\x0c\x00\x1f\x7f é🦀";
+
+    let mut w = JsonEscape::new(Log::default());
+    w.write_str(input).unwrap();
+    w.write_char('"').unwrap();
+    w.flush().unwrap();
+    assert_eq!(
+        w.free().text,
+        "a\\\"\\\\\\n\\r\\t\\b\\f\\u0000\\u001f\x7f é🦀\\\""
+    );
+
+    let mut w = CsvEscape::new(Log::default());
+    w.write_str(input).unwrap();
+    w.write_char('"').unwrap();
+    assert_eq!(w.free().text, "a\"\"\\\n\r\t\x08\x0c\x00\x1f\x7f é🦀\"\"");
+
+    let mut w = CEscape::new(Log::default());
+    w.write_str(input).unwrap();
+    w.write_char('"').unwrap();
+    assert_eq!(
+        w.free().text,
+        "a\\\"\\\\\\n\\r\\t\\010\\014\\000\\037\\177 é🦀\\\""
+    );
+
+    assert_eq!(JsonEscape::new(Broken).write_str("\""), Err(()));
+    assert_eq!(CsvEscape::new(Broken).write_str("a"), Err(()));
+}
+
+#[test]
+fn ignore() {
+    let mut w = Ignore::new(Broken);
+    uwrite!(w, "{} {}", 1, 'x').unwrap();
+    w.write_bytes(b"\x00").unwrap();
+    w.flush().unwrap();
+    w.free();
+}
+
+#[test]
+fn line_buffered() {
+    let mut w = LineBuffered::<_, 8>::new(Vec::new());
+
+    uwrite!(w, "{}", 'é').unwrap();
+    uwrite!(w, " {}", 1).unwrap();
+    w.write_char('\n').unwrap();
+    // longer than the buffer
+    w.write_str("0123456789").unwrap();
+    w.write_bytes(b"\xff").unwrap();
+    w.write_str("!").unwrap();
+    w.flush().unwrap();
+    assert_eq!(w.free(), b"\xc3\xa9 1\n0123456789\xff!");
+
+    // newlines only write the buffered line out; `flush` also flushes the underlying writer
+    #[derive(Default)]
+    struct Flushes {
+        text: String,
+        flushes: usize,
+    }
+
+    impl uWrite for Flushes {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.text.push_str(s);
+            Ok(())
+        }
+
+        fn flush(&mut self) -> Result<(), Infallible> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    let mut w = LineBuffered::<_, 8>::new(Flushes::default());
+    w.write_str("a\nb\nc").unwrap();
+    assert_eq!(w.free().flushes, 0);
+
+    let mut w = LineBuffered::<_, 8>::new(Flushes::default());
+    w.write_str("a\nb").unwrap();
+    w.flush().unwrap();
+    let inner = w.free();
+    assert_eq!((inner.text.as_str(), inner.flushes), ("a\nb", 1));
+}
+
+#[test]
+fn line_prefixed() {
+    let mut w = LinePrefixed::new(Log::default(), |w: &mut Log| w.write_str("> "));
+    w.write_str("a").unwrap();
+    w.write_char('é').unwrap();
+    w.write_str("\n\nb\nc").unwrap();
+    w.flush().unwrap();
+    assert_eq!(w.free().text, "> aé\n> \n> b\n> c");
+
+    let mut w = LinePrefixed::new(Vec::new(), |w: &mut Vec<u8>| w.write_str("> "));
+    w.write_str("a").unwrap();
+    w.write_bytes(b"\n\xff\n").unwrap();
+    assert_eq!(w.free(), b"> a\n> \xff\n");
+
+    // errors of the prefix and of the underlying writer are reported
+    let mut w = LinePrefixed::new(Broken, |w: &mut Broken| w.write_str("> "));
+    assert_eq!(w.write_str("a"), Err(()));
+    let mut w = LinePrefixed::new(Broken, |_: &mut Broken| Ok(()));
+    assert_eq!(w.write_str("a"), Err(()));
+    assert_eq!(w.write_str(""), Ok(()));
+}
+
+#[test]
+fn ring_buffer() {
+    let mut log = RingBuffer::<16>::new();
+    assert_eq!(log.as_strs(), ("", ""));
+
+    let mut all = String::new();
+    for (i, piece) in ["a", "éé", "🦀", "€uro", "0123456789abcdefghij", "", "z"]
+        .iter()
+        .cycle()
+        .take(50)
+        .enumerate()
+    {
+        if i % 3 == 0 {
+            log.write_char('ß').unwrap();
+            all.push('ß');
+        } else {
+            log.write_str(piece).unwrap();
+            all.push_str(piece);
+        }
+
+        let (older, newer) = log.as_strs();
+        let retained = [older, newer].concat();
+        assert!(all.ends_with(&retained));
+        // at most 3 bytes of a character are lost on each side of the wrap point
+        assert!(retained.len() >= cmp::min(all.len(), 16 - 6));
+        assert_eq!(log.iter().collect::<String>(), retained);
+    }
+
+    log.clear();
+    assert_eq!(log.iter().count(), 0);
+
+    // characters larger than the buffer are dropped
+    let mut log = RingBuffer::<2>::new();
+    log.write_str("a🦀b").unwrap();
+    assert_eq!(log.as_strs(), ("", "b"));
+}
+
+#[test]
+fn tee() {
+    let mut w = Tee::new((Log::default(), Vec::new(), String::new()));
+    uwrite!(w, "{} {:?}", 'é', [1]).unwrap();
+    w.flush().unwrap();
+    let (log, bytes, text) = w.free();
+    assert_eq!(log.text, "é [1]");
+    assert_eq!(bytes, "é [1]".as_bytes());
+    assert_eq!(text, "é [1]");
+
+    // raw bytes can be written when every writer accepts them
+    let mut w = Tee::new((Vec::new(), Ignore::new(Broken)));
+    w.write_str("a").unwrap();
+    w.write_bytes(b"\xff").unwrap();
+    assert_eq!(w.free().0, b"a\xff");
+    let mut w = Tee::with_policy((Broken, Vec::new()), PerSink);
+    assert_eq!(w.write_bytes(b"\xff"), Err((Some(()), None)));
+    assert_eq!(w.free().1, b"\xff");
+
+    // the sinks after the failed one are skipped
+    let mut w = Tee::new((Log::default(), Broken, Log::default()));
+    assert_eq!(w.write_str("a"), Err((None, Some(()), None)));
+    let (first, _, last) = w.free();
+    assert_eq!((&*first.text, &*last.text), ("a", ""));
+
+    let mut w = Tee::with_policy((Broken, Log::default(), Broken), PerSink);
+    assert_eq!(w.write_str("a"), Err((Some(()), None, Some(()))));
+    assert_eq!(w.free().1.text, "a");
+
+    let mut w = Tee::with_policy((Broken, Log::default()), BestEffort);
+    uwrite!(w, "{}", 1).unwrap();
+    assert_eq!(w.free().1.text, "1");
+}
+
+#[test]
+fn truncate() {
+    let mut w = Truncate::<_, 8>::new(Log::default());
+    w.write_str("ab").unwrap();
+    w.write_char('€').unwrap();
+    assert!(!w.is_truncated());
+    w.write_char('é').unwrap();
+    assert!(w.is_truncated());
+    w.write_str("more").unwrap();
+    w.flush().unwrap();
+    assert_eq!(w.free().text, "ab€...");
+
+    let mut w = Truncate::<_, 4>::with_marker(Vec::new(), "|");
+    w.write_bytes(b"\x00\x01\x02").unwrap();
+    w.write_bytes(b"\x03\x04").unwrap();
+    w.write_str("x").unwrap();
+    assert_eq!(w.free(), b"\x00\x01\x02|");
+
+    // the marker counts towards the `N` bytes so a writer with `N` bytes of capacity never fills up
+    let mut w = Truncate::<heapless::String<8>, 8>::new(heapless::String::new());
+    uwrite!(w, "temperature: {}", 25).unwrap();
+    assert_eq!(w.free(), "tempe...");
+
+    let mut w = Truncate::<heapless::String<8>, 8>::new(heapless::String::new());
+    uwrite!(w, "t = {}", 5).unwrap();
+    assert!(!w.is_truncated());
+    assert_eq!(w.free(), "t = 5");
+
+    let mut w = Truncate::<heapless::String<2>, 2>::with_marker(heapless::String::new(), "~~~");
+    w.write_str("abc").unwrap();
+    assert_eq!(w.free(), "~~");
+
+    // errors of the underlying writer are still reported
+    assert_eq!(Truncate::<_, 4>::new(Broken).write_str("a"), Err(()));
+    assert_eq!(Truncate::<_, 0>::new(Broken).write_str("a"), Err(()));
+}
+
+#[test]
+fn write_adapter() {
+    let mut s = String::new();
+    let mut w = WriteAdapter(&mut s);
+    uwrite!(w, "{} {:?}", '🦀', [1, 2]).unwrap();
+    w.flush().unwrap();
+    assert_eq!(s, "🦀 [1, 2]");
+}
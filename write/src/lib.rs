@@ -1,4 +1,4 @@
-//! `μfmt`'s `uWrite` trait
+//! `μfmt`'s `uWrite`, `uWriteBytes` and `uAsyncWrite` traits
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
@@ -6,13 +6,13 @@
 #![deny(rust_2018_idioms)]
 #![deny(warnings)]
 
-#[cfg(feature = "std")]
-use core::convert::Infallible;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-#[allow(deprecated)]
-unsafe fn uninitialized<T>() -> T {
-    core::mem::uninitialized()
-}
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::Infallible;
 
 /// A collection of methods that are required / used to format a message into a stream.
 #[allow(non_camel_case_types)]
@@ -32,12 +32,78 @@ pub trait uWrite {
     /// entire byte sequence was successfully written, and this method will not return until all
     /// data has been written or an error occurs.
     fn write_char(&mut self, c: char) -> Result<(), Self::Error> {
-        let mut buf: [u8; 4] = unsafe { uninitialized() };
+        let mut buf = [0; 4];
         self.write_str(c.encode_utf8(&mut buf))
     }
+
+    /// Flushes this writer, ensuring that all intermediately buffered contents reach their
+    /// destination.
+    ///
+    /// The default implementation does nothing; writers that buffer data (e.g. adapters) should
+    /// override it and forward the call to the writer they wrap.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// A [`uWrite`]-r that can write arbitrary bytes, not just UTF-8 text
+///
+/// Implement this trait for byte-oriented writers (e.g. serial ports or ring buffers). Binary
+/// data, like length-prefixed frames or the output of `ufmt::uwrite_deferred!`, can only be written
+/// into writers that implement it; text-only writers, like `String`, don't.
+#[allow(non_camel_case_types)]
+pub trait uWriteBytes: uWrite {
+    /// Writes a byte slice, as it is, into this writer, returning whether the write succeeded.
+    ///
+    /// This method can only succeed if the entire byte slice was successfully written, and this
+    /// method will not return until all data has been written or an error occurs.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// The asynchronous counterpart of [`uWrite`]
+///
+/// Implement this trait for writers that must `.await` (e.g. a UART driven by DMA) and format into
+/// them with `ufmt::uwrite_async!`.
+#[allow(async_fn_in_trait)]
+#[allow(non_camel_case_types)]
+pub trait uAsyncWrite {
+    /// The error associated to this writer
+    type Error;
+
+    /// Writes a string slice into this writer, returning whether the write succeeded.
+    ///
+    /// This method can only succeed if the entire string slice was successfully written, and the
+    /// returned future will not complete until all data has been written or an error occurs.
+    async fn write_str(&mut self, s: &str) -> Result<(), Self::Error>;
+
+    /// Flushes this writer, ensuring that all intermediately buffered contents reach their
+    /// destination.
+    ///
+    /// The default implementation does nothing.
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl uWrite for Vec<u8> {
+    type Error = Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl uWriteBytes for Vec<u8> {
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Infallible> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl uWrite for String {
     type Error = Infallible;
 